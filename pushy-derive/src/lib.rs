@@ -0,0 +1,462 @@
+//! Derive macros for [pushy](https://docs.rs/pushy).
+//!
+//! Use them through pushy's `derive` feature rather than depending on this
+//! crate directly.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Generates a fixed-capacity structure-of-arrays container for a struct.
+///
+/// For a struct `Foo`, this generates:
+///
+/// * `FooSoA<const CAP: usize>`, holding one `PushArray` per field, all sharing
+///   the same length;
+/// * `FooRef<'a>` and `FooMut<'a>`, with references to the fields of a single
+///   element, yielded by `FooSoA::iter` and `FooSoA::iter_mut`.
+///
+/// `FooSoA` offers `new`, `len`, `is_empty`, `push`, `pop`, `clear`, `iter` and
+/// `iter_mut`, as well as a slice accessor for each field, named after it, and a
+/// mutable one, suffixed with `_mut`. The generated code is safe, so it can be
+/// used from crates that forbid `unsafe_code`.
+///
+/// Only structs with named fields and without generics are supported.
+///
+/// ```
+/// #![forbid(unsafe_code)]
+/// use pushy::PushSoA;
+///
+/// #[derive(PushSoA)]
+/// struct Sample {
+///     r#type: u8,
+///     value: u16,
+/// }
+///
+/// let mut samples: SampleSoA<4> = SampleSoA::new();
+/// samples.push(Sample { r#type: 1, value: 300 }).unwrap();
+/// samples.type_mut()[0] = 2;
+///
+/// assert_eq!(samples.r#type(), [2]);
+/// assert_eq!(samples.value(), [300]);
+/// ```
+///
+/// Fields whose accessors would clash with the methods above, or with each
+/// other, are rejected:
+///
+/// ```compile_fail
+/// use pushy::PushSoA;
+///
+/// #[derive(PushSoA)]
+/// struct Entry {
+///     len: usize,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use pushy::PushSoA;
+///
+/// #[derive(PushSoA)]
+/// struct Point {
+///     x: f32,
+///     x_mut: f32,
+/// }
+/// ```
+#[proc_macro_derive(PushSoA)]
+pub fn derive_push_soa(input: TokenStream) -> TokenStream {
+    match parse_struct(input).and_then(|input| check_names(&input).map(|()| input)) {
+        Ok(input) => generate(&input)
+            .parse()
+            .expect("pushy-derive generated invalid tokens"),
+        Err(error) => error.into_compile_error(),
+    }
+}
+
+/// The methods generated for every container, which no accessor may shadow.
+const METHODS: &[&str] = &[
+    "new", "len", "is_empty", "push", "pop", "clear", "iter", "iter_mut",
+];
+
+/// A message for `compile_error!`, pointing at the tokens it is about.
+struct Error {
+    message: String,
+    span: Span,
+}
+
+impl Error {
+    fn new(message: impl Into<String>, span: Span) -> Self {
+        Self {
+            message: message.into(),
+            span,
+        }
+    }
+
+    fn into_compile_error(self) -> TokenStream {
+        let Self { message, span } = self;
+
+        let punct = |ch, spacing| {
+            let mut punct = Punct::new(ch, spacing);
+            punct.set_span(span);
+            TokenTree::Punct(punct)
+        };
+        let mut literal = Literal::string(&message);
+        literal.set_span(span);
+        let mut args = Group::new(Delimiter::Parenthesis, TokenTree::Literal(literal).into());
+        args.set_span(span);
+
+        TokenStream::from_iter([
+            punct(':', Spacing::Joint),
+            punct(':', Spacing::Alone),
+            TokenTree::Ident(Ident::new("core", span)),
+            punct(':', Spacing::Joint),
+            punct(':', Spacing::Alone),
+            TokenTree::Ident(Ident::new("compile_error", span)),
+            punct('!', Spacing::Alone),
+            TokenTree::Group(args),
+            punct(';', Spacing::Alone),
+        ])
+    }
+}
+
+struct Struct {
+    vis: String,
+    name: String,
+    fields: Vec<Field>,
+}
+
+struct Field {
+    name: String,
+    span: Span,
+    ty: String,
+}
+
+impl Field {
+    /// The name of the field without the `r#` prefix of raw identifiers.
+    fn unraw_name(&self) -> &str {
+        self.name.strip_prefix("r#").unwrap_or(&self.name)
+    }
+
+    /// The name of the mutable accessor of this field.
+    fn mut_accessor(&self) -> String {
+        format!("{}_mut", self.unraw_name())
+    }
+
+    /// The names of both accessors of this field, as they are called.
+    fn accessors(&self) -> [String; 2] {
+        [self.unraw_name().to_owned(), self.mut_accessor()]
+    }
+}
+
+/// Skips outer attributes and visibility, returning the visibility.
+fn skip_attrs_and_vis(tokens: &[TokenTree], pos: &mut usize) -> String {
+    while let (Some(TokenTree::Punct(pound)), Some(TokenTree::Group(group))) =
+        (tokens.get(*pos), tokens.get(*pos + 1))
+    {
+        if pound.as_char() != '#' || group.delimiter() != Delimiter::Bracket {
+            break;
+        }
+        *pos += 2;
+    }
+
+    let mut vis = String::new();
+    if let Some(TokenTree::Ident(ident)) = tokens.get(*pos) {
+        if ident.to_string() == "pub" {
+            vis.push_str("pub");
+            *pos += 1;
+            if let Some(TokenTree::Group(group)) = tokens.get(*pos) {
+                if group.delimiter() == Delimiter::Parenthesis {
+                    vis.push_str(&group.to_string());
+                    *pos += 1;
+                }
+            }
+        }
+    }
+
+    vis
+}
+
+fn parse_struct(input: TokenStream) -> Result<Struct, Error> {
+    let tokens: Vec<_> = input.into_iter().collect();
+    let mut pos = 0;
+
+    let vis = skip_attrs_and_vis(&tokens, &mut pos);
+
+    match tokens.get(pos) {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "struct" => pos += 1,
+        token => {
+            return Err(Error::new(
+                "PushSoA can only be derived for structs",
+                span_of(token),
+            ))
+        }
+    }
+
+    let name = match tokens.get(pos) {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        token => return Err(Error::new("expected a struct name", span_of(token))),
+    };
+    pos += 1;
+
+    let (body, body_span) = match tokens.get(pos) {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            (group.stream(), group.span())
+        }
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
+            return Err(Error::new(
+                "PushSoA does not support generic structs",
+                punct.span(),
+            ))
+        }
+        token => {
+            return Err(Error::new(
+                "PushSoA can only be derived for structs with named fields",
+                span_of(token),
+            ))
+        }
+    };
+
+    let fields = parse_fields(body)?;
+    if fields.is_empty() {
+        return Err(Error::new("PushSoA needs at least one field", body_span));
+    }
+
+    Ok(Struct { vis, name, fields })
+}
+
+/// The span of the given token, or of the macro invocation if there is none.
+fn span_of(token: Option<&TokenTree>) -> Span {
+    token.map_or_else(Span::call_site, TokenTree::span)
+}
+
+fn parse_fields(body: TokenStream) -> Result<Vec<Field>, Error> {
+    let tokens: Vec<_> = body.into_iter().collect();
+    let mut pos = 0;
+    let mut fields = Vec::new();
+
+    while pos < tokens.len() {
+        skip_attrs_and_vis(&tokens, &mut pos);
+
+        let (name, span) = match tokens.get(pos) {
+            Some(TokenTree::Ident(ident)) => (ident.to_string(), ident.span()),
+            token => return Err(Error::new("expected a field name", span_of(token))),
+        };
+        pos += 1;
+
+        match tokens.get(pos) {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => pos += 1,
+            token => {
+                return Err(Error::new(
+                    "expected `:` after a field name",
+                    span_of(token),
+                ))
+            }
+        }
+
+        // The type goes up to the next comma outside of angle brackets
+        let mut ty = TokenStream::new();
+        let mut depth = 0usize;
+        let mut after_dash = false;
+        while let Some(token) = tokens.get(pos) {
+            if let TokenTree::Punct(punct) = token {
+                match punct.as_char() {
+                    ',' if depth == 0 => break,
+                    '<' => depth += 1,
+                    // Not the end of an angle bracket if part of `->`
+                    '>' if !after_dash => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                after_dash = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+            } else {
+                after_dash = false;
+            }
+            ty.extend([token.clone()]);
+            pos += 1;
+        }
+        // Skip the comma
+        pos += 1;
+
+        fields.push(Field {
+            name,
+            span,
+            ty: ty.to_string(),
+        });
+    }
+
+    Ok(fields)
+}
+
+/// Rejects fields whose accessors would clash with the generated methods, or
+/// with the accessors of other fields.
+fn check_names(input: &Struct) -> Result<(), Error> {
+    for (idx, field) in input.fields.iter().enumerate() {
+        let accessors = field.accessors();
+
+        if let Some(method) = accessors
+            .iter()
+            .find(|name| METHODS.contains(&name.as_str()))
+        {
+            return Err(Error::new(
+                format!(
+                    "PushSoA cannot generate an accessor for field `{}`, as it would clash with the `{method}` method",
+                    field.name
+                ),
+                field.span,
+            ));
+        }
+
+        let clashing = input.fields[..idx].iter().find(|other| {
+            other
+                .accessors()
+                .iter()
+                .any(|name| accessors.contains(name))
+        });
+        if let Some(other) = clashing {
+            return Err(Error::new(
+                format!(
+                    "PushSoA cannot generate accessors for both `{}` and `{}`, as their names clash",
+                    other.name, field.name
+                ),
+                field.span,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn generate(input: &Struct) -> String {
+    let Struct { vis, name, fields } = input;
+    let soa = format!("{name}SoA");
+    let ref_name = format!("{name}Ref");
+    let mut_name = format!("{name}Mut");
+    let first = &fields[0].name;
+
+    let mut soa_fields = String::new();
+    let mut ref_fields = String::new();
+    let mut mut_fields = String::new();
+    let mut new_fields = String::new();
+    let mut pushes = String::new();
+    let mut pops = String::new();
+    let mut accessors = String::new();
+    let mut clears = String::new();
+    let mut iters = String::new();
+    let mut iter_muts = String::new();
+    let mut zip_pattern = String::new();
+    let mut ref_init = String::new();
+
+    for (idx, field_info) in fields.iter().enumerate() {
+        let Field {
+            name: field, ty, ..
+        } = field_info;
+        let field_mut = field_info.mut_accessor();
+
+        soa_fields += &format!("{field}: ::pushy::PushArray<{ty}, CAP>,");
+        ref_fields += &format!("{vis} {field}: &'a {ty},");
+        mut_fields += &format!("{vis} {field}: &'a mut {ty},");
+        new_fields += &format!("{field}: ::pushy::PushArray::new(),");
+        pushes += &format!("self.{field}.push_checked(value.{field})?;");
+        pops += &format!("{field}: self.{field}.pop()?,");
+        clears += &format!("self.{field}.clear();");
+        accessors += &format!(
+            "
+            /// Returns the values of the `{field}` field.
+            {vis} fn {field}(&self) -> &[{ty}] {{
+                &self.{field}
+            }}
+
+            /// Returns the values of the `{field}` field, mutably.
+            {vis} fn {field_mut}(&mut self) -> &mut [{ty}] {{
+                &mut self.{field}
+            }}
+            "
+        );
+
+        if idx == 0 {
+            iters += &format!("self.{field}.iter()");
+            iter_muts += &format!("self.{field}.iter_mut()");
+            zip_pattern += field;
+        } else {
+            iters += &format!(".zip(self.{field}.iter())");
+            iter_muts += &format!(".zip(self.{field}.iter_mut())");
+            zip_pattern = format!("({zip_pattern}, {field})");
+        }
+        ref_init += &format!("{field},");
+    }
+
+    format!(
+        "
+        #[doc = \"A fixed-capacity structure-of-arrays container for [`{name}`].\"]
+        {vis} struct {soa}<const CAP: usize> {{
+            {soa_fields}
+        }}
+
+        #[doc = \"References to the fields of an element of a [`{soa}`].\"]
+        {vis} struct {ref_name}<'a> {{
+            {ref_fields}
+        }}
+
+        #[doc = \"Mutable references to the fields of an element of a [`{soa}`].\"]
+        {vis} struct {mut_name}<'a> {{
+            {mut_fields}
+        }}
+
+        impl<const CAP: usize> {soa}<CAP> {{
+            /// Creates an empty container.
+            {vis} const fn new() -> Self {{
+                Self {{ {new_fields} }}
+            }}
+
+            /// Returns the amount of elements in this container.
+            {vis} fn len(&self) -> usize {{
+                self.{first}.len()
+            }}
+
+            /// Returns true if this container holds no elements.
+            {vis} fn is_empty(&self) -> bool {{
+                self.{first}.is_empty()
+            }}
+
+            /// Splits the given value into its fields and pushes each of them.
+            ///
+            /// Returns `Error::NotEnoughCapacity` if the container is full.
+            {vis} fn push(&mut self, value: {name}) -> ::pushy::Result<()> {{
+                if self.len() == CAP {{
+                    return ::core::result::Result::Err(::pushy::Error::NotEnoughCapacity);
+                }}
+
+                // All fields share the same length, which we just checked is
+                // below the capacity, so none of these fail
+                {pushes}
+
+                ::core::result::Result::Ok(())
+            }}
+
+            /// Removes the last element and reassembles it.
+            {vis} fn pop(&mut self) -> ::core::option::Option<{name}> {{
+                ::core::option::Option::Some({name} {{ {pops} }})
+            }}
+
+            /// Removes all elements from this container.
+            {vis} fn clear(&mut self) {{
+                {clears}
+            }}
+
+            /// Returns an iterator over references to the fields of each element.
+            {vis} fn iter(&self) -> impl ::core::iter::Iterator<Item = {ref_name}<'_>> {{
+                {iters}.map(|{zip_pattern}| {ref_name} {{ {ref_init} }})
+            }}
+
+            /// Returns an iterator over mutable references to the fields of each element.
+            {vis} fn iter_mut(&mut self) -> impl ::core::iter::Iterator<Item = {mut_name}<'_>> {{
+                {iter_muts}.map(|{zip_pattern}| {mut_name} {{ {ref_init} }})
+            }}
+
+            {accessors}
+        }}
+
+        impl<const CAP: usize> ::core::default::Default for {soa}<CAP> {{
+            fn default() -> Self {{
+                Self::new()
+            }}
+        }}
+        "
+    )
+}
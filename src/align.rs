@@ -0,0 +1,128 @@
+//! Buffers with a minimum alignment for their storage.
+//!
+//! A `PushArray<u8, CAP>` only guarantees the alignment of `u8`, which is not
+//! enough for DMA engines or SIMD loads. [`AlignedPushArray`] raises it to one
+//! of the [`Alignment`]s in this module:
+//!
+//! ```
+//! use pushy::align::{AlignedPushArray, Align32};
+//!
+//! let mut dma: AlignedPushArray<u8, 512, Align32> = AlignedPushArray::new();
+//! dma.push_str("frame").unwrap();
+//!
+//! assert_eq!(dma.as_ptr() as usize % 32, 0);
+//! ```
+
+use core::{
+    fmt::{self, Debug},
+    ops::{Deref, DerefMut},
+};
+
+use crate::{LenType, PushArray};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A minimum alignment, in bytes, for the storage of an [`AlignedPushArray`].
+///
+/// This trait is sealed and implemented for [`Align2`] up to [`Align128`].
+pub trait Alignment: Copy + sealed::Sealed {
+    /// The alignment, in bytes.
+    const BYTES: usize;
+}
+
+macro_rules! impl_alignment {
+    ($($name:ident = $bytes:literal),*) => {
+        $(
+            #[doc = concat!("Aligns storage to ", stringify!($bytes), " bytes.")]
+            #[derive(Debug, Clone, Copy)]
+            #[repr(align($bytes))]
+            pub struct $name;
+
+            impl sealed::Sealed for $name {}
+
+            impl Alignment for $name {
+                const BYTES: usize = $bytes;
+            }
+        )*
+    };
+}
+
+impl_alignment! {
+    Align2 = 2,
+    Align4 = 4,
+    Align8 = 8,
+    Align16 = 16,
+    Align32 = 32,
+    Align64 = 64,
+    Align128 = 128
+}
+
+/// A [`PushArray`] whose first element is aligned to at least `A::BYTES`.
+///
+/// It dereferences to the inner [`PushArray`], so the whole [`PushArray`] API
+/// is available.
+#[repr(C)]
+pub struct AlignedPushArray<T, const CAP: usize, A: Alignment, L: LenType = usize> {
+    /// Takes no space, but raises the alignment of the whole struct to `A`'s
+    _align: [A; 0],
+    // `PushArray` is `repr(C)` with its storage first, so the storage is at
+    // offset 0 of this struct
+    inner: PushArray<T, CAP, L>,
+}
+
+impl<T, const CAP: usize, A: Alignment, L: LenType> AlignedPushArray<T, CAP, A, L> {
+    /// Creates an empty [`AlignedPushArray`].
+    pub const fn new() -> Self {
+        Self {
+            _align: [],
+            inner: PushArray::new(),
+        }
+    }
+
+    /// Returns the inner [`PushArray`], which loses the alignment guarantee
+    /// once moved out.
+    pub fn into_inner(self) -> PushArray<T, CAP, L> {
+        self.inner
+    }
+}
+
+impl<T, const CAP: usize, A: Alignment, L: LenType> Deref for AlignedPushArray<T, CAP, A, L> {
+    type Target = PushArray<T, CAP, L>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, const CAP: usize, A: Alignment, L: LenType> DerefMut for AlignedPushArray<T, CAP, A, L> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T, const CAP: usize, A: Alignment, L: LenType> Default for AlignedPushArray<T, CAP, A, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const CAP: usize, A: Alignment, L: LenType> Clone
+    for AlignedPushArray<T, CAP, A, L>
+{
+    fn clone(&self) -> Self {
+        Self {
+            _align: [],
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Debug, const CAP: usize, A: Alignment, L: LenType> Debug
+    for AlignedPushArray<T, CAP, A, L>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
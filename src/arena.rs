@@ -0,0 +1,153 @@
+use crate::{PushSlab, Result};
+
+/// A handle to a value stored in a [`GenArena`].
+///
+/// Besides the slot index, a key remembers the generation of the slot when the
+/// value was inserted, so keys to removed values can't be used to access
+/// values inserted later into the same slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GenKey {
+    index: usize,
+    generation: u32,
+}
+
+impl GenKey {
+    /// The index of the slot this key points to.
+    pub fn index(self) -> usize {
+        self.index
+    }
+
+    /// The generation of the slot at the time this key was created.
+    pub fn generation(self) -> u32 {
+        self.generation
+    }
+}
+
+/// A fixed-capacity generational arena.
+///
+/// Works like a [`PushSlab`](crate::PushSlab), but keys carry a generation
+/// which is bumped every time a slot is freed. Stale keys, whose values were
+/// removed, are detected even after their slot gets reused.
+///
+/// ```
+/// use pushy::GenArena;
+///
+/// let mut entities: GenArena<&str, 4> = GenArena::new();
+///
+/// let player = entities.insert("player").unwrap();
+/// assert_eq!(entities.remove(player), Some("player"));
+///
+/// // The slot is reused, but the old key doesn't see the new value
+/// let enemy = entities.insert("enemy").unwrap();
+/// assert_eq!(enemy.index(), player.index());
+/// assert_eq!(entities.get(player), None);
+/// assert_eq!(entities.get(enemy), Some(&"enemy"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GenArena<T, const CAP: usize> {
+    slab: PushSlab<T, CAP>,
+    /// The current generation of every slot, bumped when its value is removed
+    generations: [u32; CAP],
+}
+
+impl<T, const CAP: usize> GenArena<T, CAP> {
+    /// Creates an empty [`GenArena`].
+    pub const fn new() -> Self {
+        Self {
+            slab: PushSlab::new(),
+            generations: [0; CAP],
+        }
+    }
+
+    /// Returns the amount of values stored in this [`GenArena`].
+    pub fn len(&self) -> usize {
+        self.slab.len()
+    }
+
+    /// Returns true if this [`GenArena`] holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.slab.is_empty()
+    }
+
+    /// Returns true if this [`GenArena`] can't hold any more values.
+    pub fn is_full(&self) -> bool {
+        self.slab.is_full()
+    }
+
+    /// Stores a value in this [`GenArena`], returning its key.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity)
+    /// if all slots are taken.
+    pub fn insert(&mut self, value: T) -> Result<GenKey> {
+        let index = self.slab.insert(value)?;
+
+        Ok(GenKey {
+            index,
+            generation: self.generations[index],
+        })
+    }
+
+    /// Returns the index of the slot the given key points to, if the key isn't
+    /// stale.
+    fn index(&self, key: GenKey) -> Option<usize> {
+        let generation = *self.generations.get(key.index)?;
+
+        (generation == key.generation).then_some(key.index)
+    }
+
+    /// Returns a reference to the value with the given key, or `None` if the
+    /// key is stale.
+    pub fn get(&self, key: GenKey) -> Option<&T> {
+        self.slab.get(self.index(key)?)
+    }
+
+    /// Returns a mutable reference to the value with the given key, or `None`
+    /// if the key is stale.
+    pub fn get_mut(&mut self, key: GenKey) -> Option<&mut T> {
+        let index = self.index(key)?;
+
+        self.slab.get_mut(index)
+    }
+
+    /// Returns true if the given key points to a stored value.
+    pub fn contains(&self, key: GenKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes the value with the given key, freeing its slot and
+    /// invalidating all keys to it.
+    pub fn remove(&mut self, key: GenKey) -> Option<T> {
+        let index = self.index(key)?;
+        let value = self.slab.remove(index)?;
+        self.generations[index] = self.generations[index].wrapping_add(1);
+
+        Some(value)
+    }
+
+    /// Removes all values from this [`GenArena`], invalidating all keys.
+    pub fn clear(&mut self) {
+        for (index, _) in self.slab.iter() {
+            self.generations[index] = self.generations[index].wrapping_add(1);
+        }
+        self.slab.clear();
+    }
+
+    /// Returns an iterator over the keys and values of this [`GenArena`], in
+    /// index order.
+    pub fn iter(&self) -> impl Iterator<Item = (GenKey, &T)> {
+        self.slab.iter().map(|(index, value)| {
+            let key = GenKey {
+                index,
+                generation: self.generations[index],
+            };
+
+            (key, value)
+        })
+    }
+}
+
+impl<T, const CAP: usize> Default for GenArena<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,131 @@
+//! [COBS](https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing)
+//! framing, which encodes data without any zero bytes so that zero can
+//! delimit frames.
+
+use crate::{Error, LenType, PushArray, Result};
+
+impl<const CAP: usize, L: LenType> PushArray<u8, CAP, L> {
+    /// Push the COBS encoding of `data` to the back of this [`PushArray`].
+    ///
+    /// The encoding takes at most `data.len() + data.len() / 254 + 1` bytes, and
+    /// contains no zero bytes. The zero delimiter that usually follows a frame is
+    /// not pushed.
+    ///
+    /// If the encoding does not fit, nothing is pushed and
+    /// [`Error::NotEnoughCapacity`] is returned.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let mut frame: PushArray<u8, 8> = PushArray::new();
+    /// frame.push_cobs_encoded(&[0x11, 0x00, 0x22, 0x33]).unwrap();
+    /// frame.push(0x00);
+    ///
+    /// assert_eq!(frame, [0x02, 0x11, 0x03, 0x22, 0x33, 0x00]);
+    /// # }
+    /// ```
+    pub fn push_cobs_encoded(&mut self, data: &[u8]) -> Result<()> {
+        let original_len = self.len();
+
+        let result = self.encode_cobs(data);
+        if result.is_err() {
+            // Safety: bytes are trivially droppable, so rolling back is just
+            //         a matter of restoring the previous length
+            unsafe { self.set_len(original_len) };
+        }
+
+        result
+    }
+
+    fn encode_cobs(&mut self, data: &[u8]) -> Result<()> {
+        // Each block starts with a code: the distance to the next zero byte,
+        // which gets filled in once the block ends
+        let mut code_idx = self.len();
+        self.push_checked(0)?;
+        let mut code = 1;
+
+        for (idx, &byte) in data.iter().enumerate() {
+            if byte != 0 {
+                self.push_checked(byte)?;
+                code += 1;
+            }
+
+            // A block ends at a zero byte, or after 254 non-zero bytes. A full
+            // block doesn't stand for a zero, so there's no need for another
+            // block after it if the data ends there
+            let is_last = idx + 1 == data.len();
+            if byte == 0 || (code == 0xFF && !is_last) {
+                self[code_idx] = code;
+                code_idx = self.len();
+                self.push_checked(0)?;
+                code = 1;
+            }
+        }
+        self[code_idx] = code;
+
+        Ok(())
+    }
+
+    /// Decodes the COBS frame at the start of `encoded`, pushing the decoded
+    /// data to the back of this [`PushArray`].
+    ///
+    /// The frame ends at the first zero byte, which is consumed as its
+    /// delimiter, or at the end of `encoded`. Returns the amount of bytes of
+    /// `encoded` consumed, so that further frames can be decoded from the rest.
+    ///
+    /// Returns [`Error::NotEnoughElements`] if the frame is truncated, or
+    /// [`Error::NotEnoughCapacity`] if the decoded data does not fit. Nothing is
+    /// pushed in either case.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let received = [0x02, 0x11, 0x03, 0x22, 0x33, 0x00, 0x02, 0x44, 0x00];
+    ///
+    /// let mut data: PushArray<u8, 8> = PushArray::new();
+    /// let consumed = data.push_cobs_decoded(&received).unwrap();
+    /// assert_eq!(data, [0x11, 0x00, 0x22, 0x33]);
+    ///
+    /// data.clear();
+    /// data.push_cobs_decoded(&received[consumed..]).unwrap();
+    /// assert_eq!(data, [0x44]);
+    /// ```
+    pub fn push_cobs_decoded(&mut self, encoded: &[u8]) -> Result<usize> {
+        let original_len = self.len();
+
+        let result = self.decode_cobs(encoded);
+        if result.is_err() {
+            // Safety: bytes are trivially droppable, so rolling back is just
+            //         a matter of restoring the previous length
+            unsafe { self.set_len(original_len) };
+        }
+
+        result
+    }
+
+    fn decode_cobs(&mut self, encoded: &[u8]) -> Result<usize> {
+        let mut pos = 0;
+
+        loop {
+            let code = match encoded.get(pos) {
+                None => return Ok(pos),
+                Some(0) => return Ok(pos + 1),
+                Some(&code) => usize::from(code),
+            };
+            pos += 1;
+
+            let block = encoded
+                .get(pos..pos + code - 1)
+                .filter(|block| !block.contains(&0))
+                .ok_or(Error::NotEnoughElements)?;
+            self.copy_from_slice(block)?;
+            pos += block.len();
+
+            // Blocks shorter than the maximum stand for a zero byte, unless
+            // they're the last one of the frame
+            let is_last = matches!(encoded.get(pos), None | Some(0));
+            if code < 0xFF && !is_last {
+                self.push_checked(0)?;
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Checksums computed over, and appended to, byte buffers.
+//!
+//! The checksums are computed bit by bit rather than with lookup tables, which
+//! is slower but keeps code size small on microcontrollers.
+
+use crate::{LenType, PushArray, Result};
+
+/// CRC-16/CCITT-FALSE: polynomial 0x1021, initial value 0xFFFF, not reflected.
+fn crc16_ccitt(data: &[u8]) -> u16 {
+    data.iter().fold(0xFFFF, |crc, &byte| {
+        (0..8).fold(crc ^ (u16::from(byte) << 8), |crc, _| match crc & 0x8000 {
+            0 => crc << 1,
+            _ => (crc << 1) ^ 0x1021,
+        })
+    })
+}
+
+/// CRC-32 as used by Ethernet and zlib: polynomial 0x04C11DB7, reflected.
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| match crc & 1 {
+            0 => crc >> 1,
+            _ => (crc >> 1) ^ 0xEDB8_8320,
+        })
+    })
+}
+
+impl<const CAP: usize, L: LenType> PushArray<u8, CAP, L> {
+    /// Computes the CRC-16/CCITT-FALSE of the contents of this [`PushArray`] and
+    /// pushes it, big-endian.
+    ///
+    /// If it does not fit, nothing is pushed and
+    /// [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity) is returned.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut frame: PushArray<u8, 16> = PushArray::new();
+    /// frame.push_str("123456789").unwrap();
+    /// frame.push_crc16_ccitt().unwrap();
+    ///
+    /// assert_eq!(frame[9..], [0x29, 0xB1]);
+    /// assert!(frame.verify_crc16_ccitt());
+    /// ```
+    pub fn push_crc16_ccitt(&mut self) -> Result<()> {
+        let crc = crc16_ccitt(self);
+
+        self.copy_from_slice(&crc.to_be_bytes())
+    }
+
+    /// Checks if the last two bytes of this [`PushArray`] are the big-endian
+    /// CRC-16/CCITT-FALSE of the bytes before them.
+    pub fn verify_crc16_ccitt(&self) -> bool {
+        match self.len().checked_sub(2) {
+            Some(split) => {
+                let (data, crc) = self.split_at(split);
+                crc16_ccitt(data).to_be_bytes() == crc
+            }
+            None => false,
+        }
+    }
+
+    /// Computes the CRC-32 of the contents of this [`PushArray`] and pushes it,
+    /// little-endian.
+    ///
+    /// This is the CRC-32 used by Ethernet, zlib and PNG.
+    ///
+    /// If it does not fit, nothing is pushed and
+    /// [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity) is returned.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut frame: PushArray<u8, 16> = PushArray::new();
+    /// frame.push_str("123456789").unwrap();
+    /// frame.push_crc32().unwrap();
+    ///
+    /// assert_eq!(frame[9..], 0xCBF43926_u32.to_le_bytes());
+    /// assert!(frame.verify_crc32());
+    /// ```
+    pub fn push_crc32(&mut self) -> Result<()> {
+        let crc = crc32(self);
+
+        self.copy_from_slice(&crc.to_le_bytes())
+    }
+
+    /// Checks if the last four bytes of this [`PushArray`] are the little-endian
+    /// CRC-32 of the bytes before them.
+    pub fn verify_crc32(&self) -> bool {
+        match self.len().checked_sub(4) {
+            Some(split) => {
+                let (data, crc) = self.split_at(split);
+                crc32(data).to_le_bytes() == crc
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use core::{fmt, iter::FusedIterator};
+
+use crate::{Error, LenType, PushArray, Result};
+
+/// A consuming cursor over the elements of a [`PushArray`].
+///
+/// Elements are moved out one by one with [`Iterator::next`], and can be looked
+/// at before that with [`peek`](Cursor::peek) and
+/// [`remaining_slice`](Cursor::remaining_slice).
+///
+/// ```
+/// use pushy::{Cursor, PushArray};
+///
+/// let tokens: PushArray<&str, 8> = PushArray::from_split_whitespace("led set 1").unwrap();
+/// let mut cursor = Cursor::new(tokens);
+///
+/// assert_eq!(cursor.peek(), Some(&"led"));
+/// cursor.advance(1).unwrap();
+/// assert_eq!(cursor.next(), Some("set"));
+/// assert_eq!(cursor.remaining_slice(), ["1"]);
+/// ```
+pub struct Cursor<T, const CAP: usize, L: LenType = usize> {
+    /// Holds the elements, with its length set to zero so that it doesn't
+    /// drop them
+    arr: PushArray<T, CAP, L>,
+    /// Elements in `pos..end` are still owned by the cursor
+    pos: usize,
+    end: usize,
+}
+
+impl<T, const CAP: usize, L: LenType> Cursor<T, CAP, L> {
+    /// Creates a cursor at the first element of the given [`PushArray`].
+    pub fn new(mut arr: PushArray<T, CAP, L>) -> Self {
+        let end = arr.len();
+        // Safety: the cursor takes over the ownership of the elements
+        unsafe { arr.set_len(0) };
+
+        Self { arr, pos: 0, end }
+    }
+
+    /// Returns the elements not yet consumed.
+    pub fn remaining_slice(&self) -> &[T] {
+        // Safety: the elements in `pos..end` are initialized and not yet
+        //         moved out
+        unsafe { core::slice::from_raw_parts(self.arr.as_ptr().add(self.pos), self.end - self.pos) }
+    }
+
+    /// Returns the elements not yet consumed, mutably.
+    pub fn remaining_slice_mut(&mut self) -> &mut [T] {
+        // Safety: see `remaining_slice`
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.arr.as_mut_ptr().add(self.pos),
+                self.end - self.pos,
+            )
+        }
+    }
+
+    /// Returns the next element without consuming it.
+    pub fn peek(&self) -> Option<&T> {
+        self.remaining_slice().first()
+    }
+
+    /// Skips and drops the next `n` elements.
+    ///
+    /// Returns [`Error::NotEnoughElements`] and doesn't skip anything if less
+    /// than `n` elements remain.
+    pub fn advance(&mut self, n: usize) -> Result<()> {
+        let skipped = self
+            .remaining_slice_mut()
+            .get_mut(..n)
+            .ok_or(Error::NotEnoughElements)?;
+        let skipped: *mut [T] = skipped;
+        self.pos += n;
+
+        // Safety: the skipped elements are initialized, and no longer
+        //         considered ours since `pos` was moved past them
+        unsafe { core::ptr::drop_in_place(skipped) };
+
+        Ok(())
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> Iterator for Cursor<T, CAP, L> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.pos == self.end {
+            return None;
+        }
+
+        // Safety: the element at `pos` is initialized, and moving `pos` past it
+        //         makes sure it's read only once
+        let elem = unsafe { self.arr.as_ptr().add(self.pos).read() };
+        self.pos += 1;
+
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.pos;
+
+        (len, Some(len))
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> DoubleEndedIterator for Cursor<T, CAP, L> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.pos == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        // Safety: the element at the old `end - 1` is initialized, and moving
+        //         `end` before it makes sure it's read only once
+        Some(unsafe { self.arr.as_ptr().add(self.end).read() })
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> ExactSizeIterator for Cursor<T, CAP, L> {}
+
+impl<T, const CAP: usize, L: LenType> FusedIterator for Cursor<T, CAP, L> {}
+
+impl<T, const CAP: usize, L: LenType> Drop for Cursor<T, CAP, L> {
+    fn drop(&mut self) {
+        let remaining: *mut [T] = self.remaining_slice_mut();
+
+        // Safety: the remaining elements are initialized and still ours
+        unsafe { core::ptr::drop_in_place(remaining) };
+    }
+}
+
+impl<T: fmt::Debug, const CAP: usize, L: LenType> fmt::Debug for Cursor<T, CAP, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cursor")
+            .field("remaining", &self.remaining_slice())
+            .finish()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{LenType, PushArray};
+
+/// Two [`PushArray`]s, one being written to while the other is read from.
+///
+/// The writer fills the back buffer and, once it's done, [`swap`](Self::swap)s
+/// it with the front one, which is then ready to be read (rendered,
+/// transmitted, ...). Swapping just flips an index, so no elements are moved.
+///
+/// `swap` takes `&mut self`, so sharing a `DoubleBuffer` with an interrupt
+/// handler needs the usual synchronization, such as a critical section.
+///
+/// ```
+/// use pushy::DoubleBuffer;
+///
+/// let mut frames: DoubleBuffer<u8, 4> = DoubleBuffer::new();
+///
+/// frames.back_mut().push_str("ping").unwrap();
+/// frames.swap();
+/// assert_eq!(frames.front().as_str(), Some("ping"));
+///
+/// // The back buffer now holds the old front contents
+/// frames.back_mut().clear();
+/// frames.back_mut().push_str("pong").unwrap();
+/// frames.swap();
+/// assert_eq!(frames.front().as_str(), Some("pong"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DoubleBuffer<T, const CAP: usize, L: LenType = usize> {
+    buffers: [PushArray<T, CAP, L>; 2],
+    /// Whether `buffers[1]` is the front buffer
+    flipped: bool,
+}
+
+impl<T, const CAP: usize, L: LenType> DoubleBuffer<T, CAP, L> {
+    /// Creates a [`DoubleBuffer`] with both buffers empty.
+    pub const fn new() -> Self {
+        Self {
+            buffers: [PushArray::new(), PushArray::new()],
+            flipped: false,
+        }
+    }
+
+    fn front_idx(&self) -> usize {
+        usize::from(self.flipped)
+    }
+
+    /// Returns the front buffer, the one to be read from.
+    pub fn front(&self) -> &PushArray<T, CAP, L> {
+        &self.buffers[self.front_idx()]
+    }
+
+    /// Returns the front buffer, mutably.
+    pub fn front_mut(&mut self) -> &mut PushArray<T, CAP, L> {
+        &mut self.buffers[self.front_idx()]
+    }
+
+    /// Returns the back buffer, the one to be written to.
+    pub fn back(&self) -> &PushArray<T, CAP, L> {
+        &self.buffers[1 - self.front_idx()]
+    }
+
+    /// Returns the back buffer, mutably.
+    pub fn back_mut(&mut self) -> &mut PushArray<T, CAP, L> {
+        &mut self.buffers[1 - self.front_idx()]
+    }
+
+    /// Returns both buffers at once, front first.
+    pub fn split_mut(&mut self) -> (&mut PushArray<T, CAP, L>, &mut PushArray<T, CAP, L>) {
+        let [first, second] = &mut self.buffers;
+
+        match self.flipped {
+            false => (first, second),
+            true => (second, first),
+        }
+    }
+
+    /// Turns the back buffer into the front one, and vice versa.
+    pub fn swap(&mut self) {
+        self.flipped = !self.flipped;
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> Default for DoubleBuffer<T, CAP, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,79 @@
+use core::mem::MaybeUninit;
+
+use crate::{LenType, PushArray, Result};
+
+/// A guard over the next free slot of a [`PushArray`], for constructing an
+/// element directly in the array.
+///
+/// `push` takes its element by value, which means building it on the stack
+/// first and then moving it in. For elements of several kilobytes that can be
+/// too much for a small stack; writing to the [`slot`](Emplace::slot) instead
+/// avoids the temporary. Dropping the guard without calling
+/// [`finish`](Emplace::finish) leaves the array untouched.
+///
+/// Created by [`PushArray::emplace`].
+///
+/// ```
+/// # use pushy::PushArray;
+/// use core::ptr::addr_of_mut;
+///
+/// struct Frame {
+///     id: u32,
+///     payload: [u8; 4096],
+/// }
+///
+/// let mut frames: PushArray<Frame, 2> = PushArray::new();
+///
+/// let mut slot = frames.emplace().unwrap();
+/// let frame = slot.slot().as_mut_ptr();
+/// // Safety: both fields are written in place, so the whole frame is initialized
+/// let frame = unsafe {
+///     addr_of_mut!((*frame).id).write(7);
+///     addr_of_mut!((*frame).payload).write_bytes(0xff, 1);
+///     slot.finish()
+/// };
+/// frame.payload[0] = 0;
+///
+/// assert_eq!(frames[0].id, 7);
+/// assert_eq!(frames[0].payload[..2], [0, 0xff]);
+/// ```
+pub struct Emplace<'a, T, const CAP: usize, L: LenType = usize> {
+    arr: &'a mut PushArray<T, CAP, L>,
+}
+
+impl<'a, T, const CAP: usize, L: LenType> Emplace<'a, T, CAP, L> {
+    /// Returns the slot the element is to be constructed in.
+    pub fn slot(&mut self) -> &mut MaybeUninit<T> {
+        &mut self.arr.spare_capacity_mut()[0]
+    }
+
+    /// Appends the element constructed in the [`slot`](Emplace::slot) to the
+    /// array, and returns it.
+    ///
+    /// # Safety
+    ///
+    /// The slot must have been fully initialized.
+    pub unsafe fn finish(self) -> &'a mut T {
+        let len = self.arr.len();
+
+        // Safety: `emplace` checked that there's room for one more element, and
+        //         the caller guarantees that it is initialized
+        unsafe {
+            self.arr.set_len(len + 1);
+            self.arr.get_unchecked_mut(len)
+        }
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Starts constructing an element in place, at the end of this
+    /// [`PushArray`].
+    ///
+    /// Fails with [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity)
+    /// if the array is full. See [`Emplace`] for details.
+    pub fn emplace(&mut self) -> Result<Emplace<'_, T, CAP, L>> {
+        self.ensure_capacity_for(1)?;
+
+        Ok(Emplace { arr: self })
+    }
+}
@@ -0,0 +1,85 @@
+//! Operations whose output capacity is computed from their input capacities.
+//!
+//! These rely on the incomplete `generic_const_exprs` nightly feature, and are
+//! only available with the `generic_const_exprs` feature of this crate. Crates
+//! calling them must enable the nightly feature as well, so that the computed
+//! capacities can be compared with concrete ones.
+
+use core::ptr;
+
+use crate::{LenType, PushArray};
+
+impl<T, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Joins two [`PushArray`]s into one whose capacity is the sum of theirs,
+    /// so the elements of both always fit.
+    ///
+    /// Only available with the `generic_const_exprs` feature, on nightly.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    /// # use pushy::PushArray;
+    /// let header: PushArray<u8, 4> = [0xAA, 0x01].into_iter().collect();
+    /// let payload: PushArray<u8, 8> = [1, 2, 3].into_iter().collect();
+    ///
+    /// let frame: PushArray<u8, 12> = header.concat_exact(payload);
+    /// assert_eq!(frame, [0xAA, 0x01, 1, 2, 3]);
+    /// ```
+    pub fn concat_exact<const B: usize>(
+        self,
+        other: PushArray<T, B, L>,
+    ) -> PushArray<T, { CAP + B }, L> {
+        let mut joined = PushArray::new();
+        let (a, a_len) = self.into_raw_parts();
+        let (b, b_len) = other.into_raw_parts();
+
+        // Safety: the initialized elements of both arrays are moved out of
+        //         their buffers exactly once, and `joined` has room for all
+        //         of them
+        unsafe {
+            let dst = joined.as_mut_ptr();
+            ptr::copy_nonoverlapping(a.as_ptr().cast::<T>(), dst, a_len);
+            ptr::copy_nonoverlapping(b.as_ptr().cast::<T>(), dst.add(a_len), b_len);
+            joined.set_len(a_len + b_len);
+        }
+
+        joined
+    }
+
+    /// Splits this [`PushArray`] into one of capacity `A`, holding up to the
+    /// first `A` elements, and one of the remaining capacity, holding the rest.
+    ///
+    /// Splitting at more than the capacity fails to compile. Only available
+    /// with the `generic_const_exprs` feature, on nightly.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # #![allow(incomplete_features)]
+    /// # use pushy::PushArray;
+    /// let frame: PushArray<u8, 12> = [0xAA, 0x01, 1, 2, 3].into_iter().collect();
+    ///
+    /// let (header, payload): (PushArray<u8, 2>, PushArray<u8, 10>) = frame.split_exact();
+    /// assert_eq!(header, [0xAA, 0x01]);
+    /// assert_eq!(payload, [1, 2, 3]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split_exact<const A: usize>(self) -> (PushArray<T, A, L>, PushArray<T, { CAP - A }, L>) {
+        let mut left = PushArray::new();
+        let mut right = PushArray::new();
+        let (buf, len) = self.into_raw_parts();
+        let left_len = len.min(A);
+
+        // Safety: the initialized elements are each moved out of `buf` exactly
+        //         once, the first `left_len` into `left`, which has room for
+        //         `A`, and the rest into `right`, which has room for `CAP - A`
+        unsafe {
+            let src = buf.as_ptr().cast::<T>();
+            ptr::copy_nonoverlapping(src, left.as_mut_ptr(), left_len);
+            ptr::copy_nonoverlapping(src.add(left_len), right.as_mut_ptr(), len - left_len);
+            left.set_len(left_len);
+            right.set_len(len - left_len);
+        }
+
+        (left, right)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{LenType, PushArray};
+
+/// A guard over the uninitialized tail of a [`PushArray`], for filling it from
+/// outside of Rust (e.g. through DMA or a peripheral) and then committing the
+/// written elements.
+///
+/// The guard mutably borrows the array, so the address given by
+/// [`as_mut_ptr`](ExternalFill::as_mut_ptr) stays valid for as long as the guard
+/// is alive. Dropping the guard without calling
+/// [`commit`](ExternalFill::commit) leaves the array untouched.
+///
+/// Created by [`PushArray::external_fill`].
+///
+/// ```
+/// # use pushy::PushArray;
+/// let mut rx: PushArray<u8, 64> = PushArray::new();
+/// rx.push_str("AT").unwrap();
+///
+/// let mut fill = rx.external_fill();
+/// let (addr, len) = (fill.as_mut_ptr(), fill.capacity());
+/// assert_eq!(len, 62);
+///
+/// // Stand-in for a DMA transfer into `addr`
+/// let received = b"+OK";
+/// unsafe { core::ptr::copy_nonoverlapping(received.as_ptr(), addr, received.len()) };
+///
+/// // Safety: the transfer initialized the first three elements
+/// unsafe { fill.commit(received.len()) };
+///
+/// assert_eq!(rx.as_str(), Some("AT+OK"));
+/// ```
+pub struct ExternalFill<'a, T, const CAP: usize, L: LenType = usize> {
+    arr: &'a mut PushArray<T, CAP, L>,
+}
+
+impl<T, const CAP: usize, L: LenType> ExternalFill<'_, T, CAP, L> {
+    /// Returns a pointer to the first uninitialized element of the array.
+    ///
+    /// The pointer is valid for writes of [`capacity`](ExternalFill::capacity)
+    /// elements while this guard is alive.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        let len = self.arr.len();
+
+        // Safety: `len` is at most `CAP`, so this stays within the buffer
+        unsafe { self.arr.as_mut_ptr().add(len) }
+    }
+
+    /// Returns how many elements can be written through
+    /// [`as_mut_ptr`](ExternalFill::as_mut_ptr).
+    pub fn capacity(&self) -> usize {
+        CAP - self.arr.len()
+    }
+
+    /// Marks the first `n` elements written through
+    /// [`as_mut_ptr`](ExternalFill::as_mut_ptr) as initialized, appending them
+    /// to the array.
+    ///
+    /// # Safety
+    ///
+    /// * `n` must be less than or equal to [`capacity`](ExternalFill::capacity).
+    ///
+    /// * The first `n` elements past the end of the array must have been
+    ///   initialized, and whatever was writing them must be done with them.
+    pub unsafe fn commit(self, n: usize) {
+        let new_len = self.arr.len() + n;
+
+        self.arr.set_len(new_len);
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Starts filling the uninitialized tail of this [`PushArray`] from outside
+    /// of Rust.
+    ///
+    /// See [`ExternalFill`] for details.
+    pub fn external_fill(&mut self) -> ExternalFill<'_, T, CAP, L> {
+        ExternalFill { arr: self }
+    }
+}
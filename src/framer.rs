@@ -0,0 +1,84 @@
+use crate::PushArray;
+
+/// Groups a stream of samples into frames of `N` samples, each starting `HOP`
+/// samples after the previous one.
+///
+/// This is the push-based counterpart of [`PushArray::frames`], for samples
+/// that arrive one at a time, e.g. from an ADC interrupt. Frames overlap when
+/// `HOP` is less than `N`, and only the last `N` samples are ever stored.
+///
+/// `HOP` must be non-zero and at most `N`, which is checked at compile time.
+///
+/// ```
+/// use pushy::Framer;
+///
+/// let mut framer: Framer<u8, 4, 2> = Framer::new();
+/// let mut frames = Vec::new();
+///
+/// for sample in 1..=8 {
+///     if let Some(frame) = framer.push(sample) {
+///         frames.push(*frame);
+///     }
+/// }
+///
+/// assert_eq!(frames, [[1, 2, 3, 4], [3, 4, 5, 6], [5, 6, 7, 8]]);
+/// ```
+///
+/// ```compile_fail
+/// use pushy::Framer;
+///
+/// // Hops longer than the frame would skip samples
+/// let framer: Framer<u8, 4, 5> = Framer::new();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Framer<T, const N: usize, const HOP: usize> {
+    /// The samples of the frame being filled. Full right after a frame was
+    /// emitted, until the next push slides it by `HOP`.
+    samples: PushArray<T, N>,
+}
+
+impl<T, const N: usize, const HOP: usize> Framer<T, N, HOP> {
+    const VALID_HOP: () = assert!(
+        HOP != 0 && HOP <= N,
+        "the hop size must be non-zero and at most the frame length"
+    );
+
+    /// Creates a [`Framer`] with no samples.
+    pub const fn new() -> Self {
+        let () = Self::VALID_HOP;
+
+        Self {
+            samples: PushArray::new(),
+        }
+    }
+
+    /// Returns the samples stored towards the next frame.
+    pub fn pending(&self) -> &[T] {
+        &self.samples
+    }
+
+    /// Adds a sample, returning a frame if it completes one.
+    pub fn push(&mut self, sample: T) -> Option<&[T; N]> {
+        if self.samples.is_fully_initialized() {
+            // The last push emitted this frame: drop the oldest `HOP` samples
+            self.samples.initialized_mut().rotate_left(HOP);
+            self.samples.drop_tail(N - HOP);
+        }
+
+        // The array is never full at this point, so this can't fail
+        let _ = self.samples.push_checked(sample);
+
+        self.samples.as_array()
+    }
+
+    /// Drops the stored samples, so that the next frame starts from scratch.
+    pub fn clear(&mut self) {
+        self.samples.clear()
+    }
+}
+
+impl<T, const N: usize, const HOP: usize> Default for Framer<T, N, HOP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,360 @@
+//! Float-to-text routines that don't go through `core::fmt`.
+//!
+//! Digits are generated with the free-format algorithm of Burger and Dybvig
+//! ("Printing Floating-Point Numbers Quickly and Accurately"), which yields the
+//! shortest digits that parse back to the same value. It works on exact big
+//! integers, so unlike Grisu it never has to fall back to another algorithm,
+//! and the output doesn't depend on the platform's float arithmetic.
+
+use core::cmp::Ordering;
+
+use crate::itoa;
+
+/// Enough room for the longest representation of any `f64`, such as
+/// `-0.00001234567890123456` or `-1.2345678901234567e-308`
+pub(crate) const MAX_FLOAT_LEN: usize = 24;
+
+/// Enough shortest digits for any `f64`
+const MAX_DIGITS: usize = 17;
+
+/// Values in `1e-5..1e16` are written without an exponent
+const MIN_FIXED_EXP: i32 = -5;
+const MAX_FIXED_EXP: i32 = 15;
+
+pub(crate) fn f32_shortest(value: f32, buf: &mut [u8; MAX_FLOAT_LEN]) -> &[u8] {
+    if let Some(special) = special(
+        value.is_nan(),
+        value.is_infinite(),
+        value.is_sign_negative(),
+    ) {
+        return special;
+    }
+
+    let bits = value.to_bits();
+    let biased_exp = ((bits >> 23) & 0xff) as i32;
+    let fraction = u64::from(bits & 0x7f_ffff);
+    let (mant, exp, lower_closer) = match biased_exp {
+        0 => (fraction, -149, false),
+        _ => (
+            fraction | 1 << 23,
+            biased_exp - 150,
+            fraction == 0 && biased_exp > 1,
+        ),
+    };
+
+    shortest(value.is_sign_negative(), mant, exp, lower_closer, buf)
+}
+
+pub(crate) fn f64_shortest(value: f64, buf: &mut [u8; MAX_FLOAT_LEN]) -> &[u8] {
+    if let Some(special) = special(
+        value.is_nan(),
+        value.is_infinite(),
+        value.is_sign_negative(),
+    ) {
+        return special;
+    }
+
+    let bits = value.to_bits();
+    let biased_exp = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & 0xf_ffff_ffff_ffff;
+    let (mant, exp, lower_closer) = match biased_exp {
+        0 => (fraction, -1074, false),
+        _ => (
+            fraction | 1 << 52,
+            biased_exp - 1075,
+            fraction == 0 && biased_exp > 1,
+        ),
+    };
+
+    shortest(value.is_sign_negative(), mant, exp, lower_closer, buf)
+}
+
+/// Spells values without digits the way `Display` does.
+fn special(is_nan: bool, is_infinite: bool, is_negative: bool) -> Option<&'static [u8]> {
+    match (is_nan, is_infinite, is_negative) {
+        (true, _, _) => Some(b"NaN"),
+        (_, true, false) => Some(b"inf"),
+        (_, true, true) => Some(b"-inf"),
+        _ => None,
+    }
+}
+
+/// Writes `mant * 2^exp`. `lower_closer` is set when the next smaller float is
+/// half as far as the next larger one, which happens at powers of two.
+fn shortest(
+    negative: bool,
+    mant: u64,
+    exp: i32,
+    lower_closer: bool,
+    buf: &mut [u8; MAX_FLOAT_LEN],
+) -> &[u8] {
+    let mut digits = [0; MAX_DIGITS];
+    let (len, point) = match mant {
+        0 => {
+            digits[0] = b'0';
+            (1, 1)
+        }
+        _ => generate_digits(mant, exp, lower_closer, &mut digits),
+    };
+
+    layout(negative, &digits[..len], point, buf)
+}
+
+/// Fills `digits` with the shortest digits of `mant * 2^exp`, and returns how
+/// many there are along with the position of the decimal point: the value is
+/// `0.d1d2d3... * 10^point`.
+fn generate_digits(
+    mant: u64,
+    exp: i32,
+    lower_closer: bool,
+    digits: &mut [u8; MAX_DIGITS],
+) -> (usize, i32) {
+    // Rounding to nearest, ties to even, reads the boundaries back as this
+    // value when its mantissa is even
+    let inclusive = mant.is_multiple_of(2);
+
+    // The value is `r / s`, and the halfway points to the neighbouring floats
+    // are `(r - m_minus) / s` and `(r + m_plus) / s`
+    let (mut r, mut s, mut m_plus, mut m_minus);
+    let bound_shift = usize::from(lower_closer);
+    if exp >= 0 {
+        let exp = exp as usize;
+        r = Big::from_u64(mant);
+        r.mul_pow2(exp + 1 + bound_shift);
+        s = Big::from_u64(2 << bound_shift);
+        m_plus = Big::from_u64(1);
+        m_plus.mul_pow2(exp + bound_shift);
+        m_minus = Big::from_u64(1);
+        m_minus.mul_pow2(exp);
+    } else {
+        r = Big::from_u64(mant << (1 + bound_shift));
+        s = Big::from_u64(1);
+        s.mul_pow2(exp.unsigned_abs() as usize + 1 + bound_shift);
+        m_plus = Big::from_u64(1 << bound_shift);
+        m_minus = Big::from_u64(1);
+    }
+
+    // Estimate the position of the decimal point from the binary exponent.
+    // This is either exact or one too small, which is fixed up below
+    let log2 = exp + (u64::BITS - mant.leading_zeros()) as i32 - 1;
+    let mut point = match log2 {
+        0 => 0,
+        _ => floor_log10_pow2(log2) + 1,
+    };
+    if point >= 0 {
+        s.mul_pow10(point as usize);
+    } else {
+        let scale = point.unsigned_abs() as usize;
+        r.mul_pow10(scale);
+        m_plus.mul_pow10(scale);
+        m_minus.mul_pow10(scale);
+    }
+    while reaches_upper(&r, &m_plus, &s, inclusive) {
+        s.mul_small(10);
+        point += 1;
+    }
+
+    let mut len = 0;
+    loop {
+        r.mul_small(10);
+        m_plus.mul_small(10);
+        m_minus.mul_small(10);
+
+        let mut digit = 0;
+        while r >= s {
+            r.sub(&s);
+            digit += 1;
+        }
+
+        let low = match inclusive {
+            true => r <= m_minus,
+            false => r < m_minus,
+        };
+        let high = reaches_upper(&r, &m_plus, &s, inclusive);
+
+        if low || high {
+            // Both the digit and the next one up would read back as the
+            // value, so take whichever is nearest to it
+            let round_up = match (low, high) {
+                (true, true) => {
+                    r.mul_small(2);
+                    r >= s
+                }
+                (_, high) => high,
+            };
+            digits[len] = b'0' + digit + u8::from(round_up);
+            len += 1;
+            break;
+        }
+
+        digits[len] = b'0' + digit;
+        len += 1;
+    }
+
+    (len, point)
+}
+
+/// Returns true if `(r + m_plus) / s` reaches one, i.e. the upper halfway
+/// point is past the digits generated so far.
+fn reaches_upper(r: &Big, m_plus: &Big, s: &Big, inclusive: bool) -> bool {
+    let mut upper = *r;
+    upper.add(m_plus);
+
+    match inclusive {
+        true => upper >= *s,
+        false => upper > *s,
+    }
+}
+
+/// `floor(exp * log10(2))`, exact for `|exp| <= 2620`.
+fn floor_log10_pow2(exp: i32) -> i32 {
+    (exp * 315_653) >> 20
+}
+
+/// Writes `0.digits * 10^point`, with an exponent when the value is too large
+/// or too small to be written out legibly.
+fn layout<'a>(
+    negative: bool,
+    digits: &[u8],
+    point: i32,
+    buf: &'a mut [u8; MAX_FLOAT_LEN],
+) -> &'a [u8] {
+    let mut len = 0;
+    let mut put = |bytes: &[u8]| {
+        buf[len..len + bytes.len()].copy_from_slice(bytes);
+        len += bytes.len();
+    };
+
+    if negative {
+        put(b"-");
+    }
+
+    let (first, rest) = digits.split_at(1);
+    match point - 1 {
+        exp @ 0..=MAX_FIXED_EXP if point as usize >= digits.len() => {
+            // An integer: pad with zeros up to the decimal point
+            put(digits);
+            for _ in digits.len()..=exp as usize {
+                put(b"0");
+            }
+        }
+        0..=MAX_FIXED_EXP => {
+            let (integer, fraction) = digits.split_at(point as usize);
+            put(integer);
+            put(b".");
+            put(fraction);
+        }
+        MIN_FIXED_EXP..0 => {
+            put(b"0.");
+            for _ in point..0 {
+                put(b"0");
+            }
+            put(digits);
+        }
+        exp => {
+            put(first);
+            if !rest.is_empty() {
+                put(b".");
+                put(rest);
+            }
+            put(b"e");
+            if exp < 0 {
+                put(b"-");
+            }
+            let mut exp_buf = [0; itoa::MAX_DEC_LEN];
+            put(itoa::u32_dec(exp.unsigned_abs(), &mut exp_buf));
+        }
+    }
+
+    &buf[..len]
+}
+
+/// Enough limbs for the largest intermediate value, `r` for the smallest
+/// subnormal, which stays under `2^1136`
+const LIMBS: usize = 40;
+
+/// A fixed-width, little-endian unsigned big integer, just wide enough for the
+/// operands of [`generate_digits`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Big {
+    limbs: [u32; LIMBS],
+}
+
+impl Big {
+    fn from_u64(value: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value as u32;
+        limbs[1] = (value >> 32) as u32;
+
+        Self { limbs }
+    }
+
+    fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0;
+        for limb in &mut self.limbs {
+            let product = u64::from(*limb) * u64::from(factor) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        debug_assert_eq!(carry, 0, "Big::mul_small: overflow");
+    }
+
+    fn mul_pow2(&mut self, bits: usize) {
+        let (limb_shift, bit_shift) = (bits / 32, bits % 32);
+        debug_assert!(limb_shift < LIMBS, "Big::mul_pow2: overflow");
+
+        self.limbs.copy_within(..LIMBS - limb_shift, limb_shift);
+        self.limbs[..limb_shift].fill(0);
+        if bit_shift > 0 {
+            for idx in (1..LIMBS).rev() {
+                self.limbs[idx] =
+                    self.limbs[idx] << bit_shift | self.limbs[idx - 1] >> (32 - bit_shift);
+            }
+            self.limbs[0] <<= bit_shift;
+        }
+    }
+
+    fn mul_pow10(&mut self, mut exp: usize) {
+        const POW10_9: u32 = 1_000_000_000;
+
+        while exp >= 9 {
+            self.mul_small(POW10_9);
+            exp -= 9;
+        }
+        self.mul_small(10u32.pow(exp as u32));
+    }
+
+    fn add(&mut self, other: &Self) {
+        let mut carry = false;
+        for (limb, other) in self.limbs.iter_mut().zip(&other.limbs) {
+            let (sum, overflow_a) = limb.overflowing_add(*other);
+            let (sum, overflow_b) = sum.overflowing_add(u32::from(carry));
+            *limb = sum;
+            carry = overflow_a || overflow_b;
+        }
+        debug_assert!(!carry, "Big::add: overflow");
+    }
+
+    fn sub(&mut self, other: &Self) {
+        let mut borrow = false;
+        for (limb, other) in self.limbs.iter_mut().zip(&other.limbs) {
+            let (diff, overflow_a) = limb.overflowing_sub(*other);
+            let (diff, overflow_b) = diff.overflowing_sub(u32::from(borrow));
+            *limb = diff;
+            borrow = overflow_a || overflow_b;
+        }
+        debug_assert!(!borrow, "Big::sub: underflow");
+    }
+}
+
+impl PartialOrd for Big {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Big {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
@@ -0,0 +1,327 @@
+use core::{
+    fmt::{self, Debug},
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+use crate::{not_enough_capacity, raw, LenType, PushArray, Result};
+
+/// A fixed-capacity gap buffer: a sequence with a cursor, where inserting and
+/// removing at the cursor takes constant time.
+///
+/// The free space of the buffer is kept as a gap at the cursor. Moving the
+/// cursor moves the gap, which costs as much as the distance it moves, so
+/// edits that stay close to each other (like keystrokes in a text field) are
+/// cheap no matter how long the sequence is.
+///
+/// ```
+/// # #[cfg(not(feature = "no-panic-api"))] {
+/// use pushy::GapBuffer;
+///
+/// let mut line: GapBuffer<char, 16> = "helo".chars().collect();
+///
+/// line.set_cursor(3);
+/// line.insert('l').unwrap();
+/// line.set_cursor(line.len());
+/// line.insert('!').unwrap();
+///
+/// assert_eq!(line.iter().collect::<String>(), "hello!");
+///
+/// // Backspace
+/// assert_eq!(line.remove_before(), Some('!'));
+/// assert_eq!(line.iter().collect::<String>(), "hello");
+/// # }
+/// ```
+pub struct GapBuffer<T, const CAP: usize> {
+    buf: [MaybeUninit<T>; CAP],
+    /// Start of the gap, which is where the cursor is
+    gap_start: usize,
+    /// End of the gap: the elements in `gap_end..CAP` come after the cursor
+    gap_end: usize,
+}
+
+impl<T, const CAP: usize> GapBuffer<T, CAP> {
+    /// Creates an empty [`GapBuffer`], with its cursor at the start.
+    pub const fn new() -> Self {
+        Self {
+            buf: [const { MaybeUninit::uninit() }; CAP],
+            gap_start: 0,
+            gap_end: CAP,
+        }
+    }
+
+    /// Returns the amount of elements in this [`GapBuffer`].
+    pub fn len(&self) -> usize {
+        CAP - (self.gap_end - self.gap_start)
+    }
+
+    /// Returns true if this [`GapBuffer`] holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if this [`GapBuffer`] can't hold any more elements.
+    pub fn is_full(&self) -> bool {
+        self.gap_start == self.gap_end
+    }
+
+    /// Returns the position of the cursor: the amount of elements before it.
+    pub fn cursor(&self) -> usize {
+        self.gap_start
+    }
+
+    /// Moves the cursor to `position`, so that `position` elements come
+    /// before it.
+    ///
+    /// Takes time proportional to the distance moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is greater than the length.
+    pub fn set_cursor(&mut self, position: usize) {
+        assert!(position <= self.len(), "cursor position out of bounds");
+
+        let base = self.buf.as_mut_ptr();
+        if position < self.gap_start {
+            // Move the elements between the position and the cursor to the
+            // end of the gap
+            let count = self.gap_start - position;
+            // Safety: both ranges are in-bounds, and `ptr::copy` handles them
+            //         overlapping. The moved elements are initialized, and the
+            //         new bounds of the gap keep exactly those considered so
+            unsafe { ptr::copy(base.add(position), base.add(self.gap_end - count), count) };
+            self.gap_start = position;
+            self.gap_end -= count;
+        } else {
+            // Move the elements between the cursor and the position to the
+            // start of the gap
+            let count = position - self.gap_start;
+            // Safety: as above
+            unsafe { ptr::copy(base.add(self.gap_end), base.add(self.gap_start), count) };
+            self.gap_start = position;
+            self.gap_end += count;
+        }
+    }
+
+    /// Moves the cursor one element back. Returns false if it was already at
+    /// the start.
+    pub fn move_left(&mut self) -> bool {
+        let moves = self.gap_start > 0;
+        if moves {
+            self.set_cursor(self.gap_start - 1);
+        }
+
+        moves
+    }
+
+    /// Moves the cursor one element forward. Returns false if it was already
+    /// at the end.
+    pub fn move_right(&mut self) -> bool {
+        let moves = self.gap_start < self.len();
+        if moves {
+            self.set_cursor(self.gap_start + 1);
+        }
+
+        moves
+    }
+
+    /// Inserts an element at the cursor, and moves the cursor past it.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity)
+    /// if the buffer is full.
+    pub fn insert(&mut self, value: T) -> Result<()> {
+        if self.is_full() {
+            return Err(not_enough_capacity());
+        }
+
+        self.buf[self.gap_start].write(value);
+        self.gap_start += 1;
+
+        Ok(())
+    }
+
+    /// Removes the element right before the cursor and returns it, like a
+    /// backspace. Returns `None` if the cursor is at the start.
+    pub fn remove_before(&mut self) -> Option<T> {
+        self.gap_start = self.gap_start.checked_sub(1)?;
+
+        // Safety: the element was initialized, and is now part of the gap
+        Some(unsafe { self.buf[self.gap_start].assume_init_read() })
+    }
+
+    /// Removes the element right after the cursor and returns it, like a
+    /// delete. Returns `None` if the cursor is at the end.
+    pub fn remove_after(&mut self) -> Option<T> {
+        if self.gap_end == CAP {
+            return None;
+        }
+        self.gap_end += 1;
+
+        // Safety: the element was initialized, and is now part of the gap
+        Some(unsafe { self.buf[self.gap_end - 1].assume_init_read() })
+    }
+
+    /// Returns the elements before and after the cursor.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (before, rest) = self.buf.split_at(self.gap_start);
+        let after = &rest[self.gap_end - self.gap_start..];
+
+        // Safety: the elements outside of the gap are initialized
+        unsafe {
+            (
+                &*(before as *const [MaybeUninit<T>] as *const [T]),
+                &*(after as *const [MaybeUninit<T>] as *const [T]),
+            )
+        }
+    }
+
+    /// Returns the elements before and after the cursor, mutably.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (before, rest) = self.buf.split_at_mut(self.gap_start);
+        let after = &mut rest[self.gap_end - self.gap_start..];
+
+        // Safety: the elements outside of the gap are initialized
+        unsafe {
+            (
+                &mut *(before as *mut [MaybeUninit<T>] as *mut [T]),
+                &mut *(after as *mut [MaybeUninit<T>] as *mut [T]),
+            )
+        }
+    }
+
+    /// Returns the element at `index`, counting from the start of the buffer
+    /// regardless of the cursor.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let (before, after) = self.as_slices();
+
+        match index.checked_sub(before.len()) {
+            None => before.get(index),
+            Some(index) => after.get(index),
+        }
+    }
+
+    /// Returns an iterator over the elements, from the start of the buffer.
+    pub fn iter(&self) -> core::iter::Chain<core::slice::Iter<'_, T>, core::slice::Iter<'_, T>> {
+        let (before, after) = self.as_slices();
+
+        before.iter().chain(after)
+    }
+
+    /// Drops every element, and moves the cursor to the start.
+    pub fn clear(&mut self) {
+        let (gap_start, gap_end) = (self.gap_start, self.gap_end);
+        self.gap_start = 0;
+        self.gap_end = CAP;
+
+        // Safety: the elements are no longer considered initialized once the
+        //         gap spans the whole buffer, so they are dropped exactly once
+        unsafe {
+            raw::drop_range(&mut self.buf, 0, gap_start);
+            raw::drop_range(&mut self.buf, gap_end, CAP);
+        }
+    }
+
+    /// Converts this [`GapBuffer`] into a [`PushArray`] holding the same
+    /// elements, in order.
+    ///
+    /// Takes time proportional to the amount of elements after the cursor.
+    pub fn into_push_array<L: LenType>(mut self) -> PushArray<T, CAP, L> {
+        let len = self.len();
+        self.set_cursor(len);
+
+        // The elements are handed over to the array, so they must not be dropped here
+        let this = ManuallyDrop::new(self);
+        // Safety: `this` is never used or dropped after its buffer is read out
+        let buf = unsafe { ptr::read(&this.buf) };
+
+        // Safety: with the cursor at the end, the first `len` elements are
+        //         the initialized ones
+        unsafe { PushArray::from_raw_parts_with_len_type(buf, len) }
+    }
+}
+
+/// Creates a [`GapBuffer`] with the elements of the array, and the cursor at
+/// the end.
+impl<T, const CAP: usize, L: LenType> From<PushArray<T, CAP, L>> for GapBuffer<T, CAP> {
+    fn from(arr: PushArray<T, CAP, L>) -> Self {
+        let (buf, len) = arr.into_raw_parts();
+
+        Self {
+            buf,
+            gap_start: len,
+            gap_end: CAP,
+        }
+    }
+}
+
+/// Same as [`GapBuffer::into_push_array`].
+impl<T, const CAP: usize, L: LenType> From<GapBuffer<T, CAP>> for PushArray<T, CAP, L> {
+    fn from(buffer: GapBuffer<T, CAP>) -> Self {
+        buffer.into_push_array()
+    }
+}
+
+/// Collects into a [`GapBuffer`], with the cursor at the end.
+///
+/// # Panics
+///
+/// Panics if the iterator yields more than `CAP` elements.
+///
+/// Not available with the `no-panic-api` feature.
+#[cfg(not(feature = "no-panic-api"))]
+impl<T, const CAP: usize> FromIterator<T> for GapBuffer<T, CAP> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        PushArray::<T, CAP>::from_iter(iter).into()
+    }
+}
+
+impl<T, const CAP: usize> Default for GapBuffer<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const CAP: usize> Clone for GapBuffer<T, CAP> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        let (before, after) = self.as_slices();
+
+        for value in before.iter().chain(after).cloned() {
+            // The clone has the same capacity, so everything fits
+            let _ = clone.insert(value);
+        }
+        clone.set_cursor(self.cursor());
+
+        clone
+    }
+}
+
+/// Formats the elements as a slice, or with the cursor position under `{:#?}`.
+impl<T: Debug, const CAP: usize> Debug for GapBuffer<T, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let (before, after) = self.as_slices();
+            f.debug_struct("GapBuffer")
+                .field("before", &before)
+                .field("after", &after)
+                .field("capacity", &CAP)
+                .finish()
+        } else {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+}
+
+impl<T: PartialEq, const CAP: usize> PartialEq for GapBuffer<T, CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const CAP: usize> Eq for GapBuffer<T, CAP> {}
+
+impl<T, const CAP: usize> Drop for GapBuffer<T, CAP> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
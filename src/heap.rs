@@ -0,0 +1,211 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{LenType, PushArray, Result};
+
+/// A mutable reference to the greatest element of a heap formed by a
+/// [`PushArray`], which restores the heap property when dropped.
+///
+/// Created by [`PushArray::peek_heap_mut`].
+///
+/// ```
+/// # #[cfg(not(feature = "no-panic-api"))] {
+/// # use pushy::PushArray;
+/// let mut deadlines: PushArray<u32, 8> = [40, 10, 30].into_iter().collect();
+/// deadlines.make_heap();
+///
+/// // Lower the latest deadline, without popping and pushing it back
+/// *deadlines.peek_heap_mut().unwrap() = 5;
+///
+/// assert_eq!(deadlines.pop_heap(), Some(30));
+/// assert_eq!(deadlines.pop_heap(), Some(10));
+/// assert_eq!(deadlines.pop_heap(), Some(5));
+/// # }
+/// ```
+pub struct PeekHeapMut<'a, T: Ord, const CAP: usize, L: LenType = usize> {
+    arr: &'a mut PushArray<T, CAP, L>,
+}
+
+impl<T: Ord, const CAP: usize, L: LenType> PeekHeapMut<'_, T, CAP, L> {
+    /// Removes the peeked element from the heap and returns it.
+    pub fn pop(this: Self) -> T {
+        let mut this = core::mem::ManuallyDrop::new(this);
+
+        // The heap isn't empty, since there was an element to peek at, and
+        // `pop_heap` restores the heap property on its own
+        this.arr.pop_heap().expect("a peeked heap can't be empty")
+    }
+}
+
+impl<T: Ord, const CAP: usize, L: LenType> Deref for PeekHeapMut<'_, T, CAP, L> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.arr[0]
+    }
+}
+
+impl<T: Ord, const CAP: usize, L: LenType> DerefMut for PeekHeapMut<'_, T, CAP, L> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.arr.initialized_mut()[0]
+    }
+}
+
+impl<T: Ord, const CAP: usize, L: LenType> Drop for PeekHeapMut<'_, T, CAP, L> {
+    fn drop(&mut self) {
+        let len = self.arr.len();
+        self.arr.sift_down(0, len);
+    }
+}
+
+/// In-place binary heap operations.
+///
+/// These treat the initialized elements as a max-heap, like `BinaryHeap`, so a
+/// [`PushArray`] can be used as a priority queue without moving its data.
+impl<T: Ord, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Rearranges the initialized elements into a max-heap, in linear time.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let mut tasks: PushArray<u8, 8> = [3, 1, 4, 1, 5].into_iter().collect();
+    /// tasks.make_heap();
+    ///
+    /// assert_eq!(tasks.pop_heap(), Some(5));
+    /// assert_eq!(tasks.pop_heap(), Some(4));
+    /// # }
+    /// ```
+    pub fn make_heap(&mut self) {
+        let len = self.len();
+        for idx in (0..len / 2).rev() {
+            self.sift_down(idx, len);
+        }
+    }
+
+    /// Pushes an element into the heap formed by the initialized elements.
+    ///
+    /// The elements must form a heap already, either by calling
+    /// [`make_heap`](Self::make_heap) or by only using heap operations.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`] if the array is full.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut tasks: PushArray<u8, 3> = PushArray::new();
+    /// tasks.push_heap(2).unwrap();
+    /// tasks.push_heap(7).unwrap();
+    /// tasks.push_heap(5).unwrap();
+    /// assert!(tasks.push_heap(1).is_err());
+    ///
+    /// assert_eq!(tasks.pop_heap(), Some(7));
+    /// assert_eq!(tasks.pop_heap(), Some(5));
+    /// assert_eq!(tasks.pop_heap(), Some(2));
+    /// assert_eq!(tasks.pop_heap(), None);
+    /// ```
+    pub fn push_heap(&mut self, value: T) -> Result<()> {
+        self.push_checked(value)?;
+        self.sift_up(self.len() - 1);
+
+        Ok(())
+    }
+
+    /// Removes the greatest element from the heap formed by the initialized
+    /// elements and returns it.
+    ///
+    /// The elements must form a heap already, either by calling
+    /// [`make_heap`](Self::make_heap) or by only using heap operations.
+    pub fn pop_heap(&mut self) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+        self.initialized_mut().swap(0, last);
+        let greatest = self.pop();
+        self.sift_down(0, last);
+
+        greatest
+    }
+
+    /// Returns a mutable reference to the greatest element of the heap formed
+    /// by the initialized elements, or `None` if empty.
+    ///
+    /// The element is moved back into place once the returned guard is
+    /// dropped. See [`PeekHeapMut`] for details.
+    ///
+    /// The elements must form a heap already, either by calling
+    /// [`make_heap`](Self::make_heap) or by only using heap operations.
+    pub fn peek_heap_mut(&mut self) -> Option<PeekHeapMut<'_, T, CAP, L>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekHeapMut { arr: self })
+        }
+    }
+
+    /// Keeps only the elements of the heap for which `f` returns true, then
+    /// restores the heap property.
+    ///
+    /// Removed elements are dropped right away, and the heap is rebuilt once
+    /// at the end, in linear time. If `f` panics, the elements are all still
+    /// in the array, but may no longer form a heap.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let mut timers: PushArray<u32, 8> = [3, 8, 1, 6, 4].into_iter().collect();
+    /// timers.make_heap();
+    ///
+    /// // Cancel the timers with even deadlines
+    /// timers.retain_heap(|deadline| deadline % 2 == 1);
+    ///
+    /// assert_eq!(timers.pop_heap(), Some(3));
+    /// assert_eq!(timers.pop_heap(), Some(1));
+    /// assert_eq!(timers.pop_heap(), None);
+    /// # }
+    /// ```
+    pub fn retain_heap(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let mut idx = 0;
+        while idx < self.len() {
+            if f(&self[idx]) {
+                idx += 1;
+            } else {
+                let last = self.len() - 1;
+                self.initialized_mut().swap(idx, last);
+                drop(self.pop());
+            }
+        }
+
+        self.make_heap();
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        let heap = self.initialized_mut();
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if heap[idx] <= heap[parent] {
+                break;
+            }
+            heap.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    /// Sifts the element at `idx` down within the first `len` elements.
+    fn sift_down(&mut self, mut idx: usize, len: usize) {
+        let heap = &mut self.initialized_mut()[..len];
+        loop {
+            let left = 2 * idx + 1;
+            let right = left + 1;
+
+            let mut largest = idx;
+            if left < len && heap[left] > heap[largest] {
+                largest = left;
+            }
+            if right < len && heap[right] > heap[largest] {
+                largest = right;
+            }
+            if largest == idx {
+                break;
+            }
+
+            heap.swap(idx, largest);
+            idx = largest;
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use core::borrow::Borrow;
+
+use crate::{PushArray, Result, TryFromIterator};
+
+/// A fixed-capacity map that keeps its entries in insertion order.
+///
+/// Entries can be accessed by key or by their index in that order. Lookups by
+/// key compare against every entry, so they're linear, which is fast for the
+/// small maps this is meant for, and only requires keys to implement [`Eq`].
+///
+/// Removals come in two flavors, like in the `indexmap` crate:
+/// [`shift_remove`](Self::shift_remove) keeps the order of the remaining
+/// entries, and [`swap_remove`](Self::swap_remove) moves the last entry in
+/// place of the removed one, which is faster.
+///
+/// ```
+/// use pushy::FixedIndexMap;
+///
+/// let mut config: FixedIndexMap<&str, &str, 4> = FixedIndexMap::new();
+/// config.insert("ssid", "home").unwrap();
+/// config.insert("channel", "6").unwrap();
+/// config.insert("power", "high").unwrap();
+///
+/// // Replacing a value keeps its position
+/// config.insert("ssid", "office").unwrap();
+///
+/// let keys: Vec<_> = config.keys().copied().collect();
+/// assert_eq!(keys, ["ssid", "channel", "power"]);
+/// assert_eq!(config.get_index(0), Some((&"ssid", &"office")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedIndexMap<K, V, const CAP: usize> {
+    /// Entries in insertion order, without duplicate keys
+    entries: PushArray<(K, V), CAP>,
+}
+
+impl<K: Eq, V, const CAP: usize> FixedIndexMap<K, V, CAP> {
+    /// Creates an empty [`FixedIndexMap`].
+    pub const fn new() -> Self {
+        Self {
+            entries: PushArray::new(),
+        }
+    }
+
+    /// Returns the amount of entries in this map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if this map holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns true if this map can't hold any more entries.
+    pub fn is_full(&self) -> bool {
+        self.entries.len() == CAP
+    }
+
+    /// Returns the index of the entry with the given key, if any.
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.entries.iter().position(|(k, _)| k.borrow() == key)
+    }
+
+    /// Returns a reference to the value of the given key, if any.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+
+        Some(&self.entries[index].1)
+    }
+
+    /// Returns a mutable reference to the value of the given key, if any.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+
+        Some(&mut self.entries.initialized_mut()[index].1)
+    }
+
+    /// Returns true if this map holds a value for the given key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Returns the entry at `index` in insertion order, if any.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|(k, v)| (k, v))
+    }
+
+    /// Returns the entry at `index` in insertion order, with its value
+    /// borrowed mutably.
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.entries
+            .initialized_mut()
+            .get_mut(index)
+            .map(|(k, v)| (&*k, v))
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map already held a value for this key, it's replaced and returned,
+    /// and the entry keeps its position. Otherwise the entry is added at the end,
+    /// and `None` is returned.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity) if the
+    /// key is new and the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>> {
+        match self.get_mut(&key) {
+            Some(old) => Ok(Some(core::mem::replace(old, value))),
+            None => {
+                self.entries.push_checked((key, value))?;
+
+                Ok(None)
+            }
+        }
+    }
+
+    /// Removes the entry of the given key and returns its value, shifting the
+    /// entries after it to keep their order.
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+
+        self.shift_remove_index(index).map(|(_, v)| v)
+    }
+
+    /// Removes the entry of the given key and returns its value, moving the
+    /// last entry in its place.
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+
+        self.swap_remove_index(index).map(|(_, v)| v)
+    }
+
+    /// Removes the entry at `index` and returns it, shifting the entries after
+    /// it to keep their order.
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        if index >= self.len() {
+            return None;
+        }
+        self.entries.initialized_mut()[index..].rotate_left(1);
+
+        self.entries.pop()
+    }
+
+    /// Removes the entry at `index` and returns it, moving the last entry in
+    /// its place.
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let last = self.len().checked_sub(1)?;
+        if index > last {
+            return None;
+        }
+        self.entries.initialized_mut().swap(index, last);
+
+        self.entries.pop()
+    }
+
+    /// Removes the last entry and returns it.
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.entries.pop()
+    }
+
+    /// Returns the first entry in insertion order.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    /// Returns the last entry in insertion order.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.get_index(self.len().checked_sub(1)?)
+    }
+
+    /// Returns an iterator over the entries, in insertion order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    /// Returns an iterator over the entries in insertion order, with mutable
+    /// references to the values.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (&K, &mut V)> {
+        self.entries
+            .initialized_mut()
+            .iter_mut()
+            .map(|(k, v)| (&*k, v))
+    }
+
+    /// Returns an iterator over the keys, in insertion order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the values, in insertion order of their keys.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    /// Removes all entries from this map.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<K: Eq, V, const CAP: usize> Default for FixedIndexMap<K, V, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Two maps are equal if they hold the same entries in the same order.
+impl<K: PartialEq, V: PartialEq, const CAP: usize> PartialEq for FixedIndexMap<K, V, CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K: Eq, V: Eq, const CAP: usize> Eq for FixedIndexMap<K, V, CAP> {}
+
+/// Inserts key-value pairs into a new [`FixedIndexMap`], as with
+/// [`insert`](FixedIndexMap::insert): later values replace earlier ones for the
+/// same key, which keeps its first position. Fails if there are more than `CAP`
+/// distinct keys.
+impl<K: Eq, V, const CAP: usize> TryFromIterator<(K, V)> for FixedIndexMap<K, V, CAP> {
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self> {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            map.insert(key, value)?;
+        }
+
+        Ok(map)
+    }
+}
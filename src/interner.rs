@@ -0,0 +1,141 @@
+use crate::{Error, PushArray, Result};
+
+/// A compact handle to a string stored in an [`Interner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// The index of this symbol, in insertion order.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A fixed-capacity string interner.
+///
+/// Stores up to `SYMBOLS` distinct strings, whose contents are packed together in
+/// a buffer of `BYTES` bytes. Interning the same string twice returns the same
+/// [`Symbol`].
+///
+/// Lookups are linear in the amount of interned strings, which is fine for the
+/// small sets this is meant for.
+///
+/// ```
+/// use pushy::Interner;
+///
+/// let mut interner: Interner<64, 8> = Interner::new();
+///
+/// let get = interner.intern("GET").unwrap();
+/// let post = interner.intern("POST").unwrap();
+/// assert_ne!(get, post);
+/// assert_eq!(interner.intern("GET").unwrap(), get);
+///
+/// assert_eq!(interner.resolve(post), Some("POST"));
+/// assert_eq!(interner.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Interner<const BYTES: usize, const SYMBOLS: usize> {
+    bytes: PushArray<u8, BYTES>,
+    /// The end offset of each string in `bytes`. Strings start where the
+    /// previous one ends.
+    ends: PushArray<u32, SYMBOLS>,
+}
+
+impl<const BYTES: usize, const SYMBOLS: usize> Interner<BYTES, SYMBOLS> {
+    const CAPACITIES_FIT_U32: () = assert!(
+        SYMBOLS <= u32::MAX as usize && BYTES <= u32::MAX as usize,
+        "the capacities of this Interner do not fit in a u32"
+    );
+
+    /// Creates an empty [`Interner`].
+    pub const fn new() -> Self {
+        let () = Self::CAPACITIES_FIT_U32;
+
+        Self {
+            bytes: PushArray::new(),
+            ends: PushArray::new(),
+        }
+    }
+
+    /// Returns the amount of distinct strings interned.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns true if no strings were interned.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    fn range(&self, index: usize) -> Option<(usize, usize)> {
+        let end = *self.ends.get(index)?;
+        let start = match index {
+            0 => 0,
+            _ => self.ends[index - 1],
+        };
+
+        Some((start as usize, end as usize))
+    }
+
+    /// Returns the symbol of the given string, if it was interned.
+    pub fn get(&self, value: &str) -> Option<Symbol> {
+        (0..self.len())
+            .find(|&index| self.resolve(Symbol(index as u32)) == Some(value))
+            .map(|index| Symbol(index as u32))
+    }
+
+    /// Interns the given string, returning its symbol.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`] if the string wasn't interned before
+    /// and there's no room left for it, either in the byte buffer or in the
+    /// symbol table. The interner is left unchanged in that case.
+    pub fn intern(&mut self, value: &str) -> Result<Symbol> {
+        if let Some(symbol) = self.get(value) {
+            return Ok(symbol);
+        }
+
+        if self.ends.len() == SYMBOLS || BYTES - self.bytes.len() < value.len() {
+            return Err(Error::NotEnoughCapacity);
+        }
+
+        self.bytes.push_str(value)?;
+        // `BYTES` fits in a u32, which is checked when creating the interner
+        self.ends.push_checked(self.bytes.len() as u32)?;
+
+        Ok(Symbol((self.ends.len() - 1) as u32))
+    }
+
+    /// Returns the string of the given symbol, or `None` if its index is out
+    /// of range.
+    ///
+    /// Symbols from other interners are not detected: they resolve to
+    /// whichever string of this interner has the same index.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        let (start, end) = self.range(symbol.index())?;
+        let bytes = &self.bytes[start..end];
+
+        // Safety: `bytes` was copied from a `&str` in `intern`
+        Some(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Returns an iterator over the symbols and strings of this interner, in
+    /// insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        (0..self.len()).filter_map(|index| {
+            let symbol = Symbol(index as u32);
+            Some((symbol, self.resolve(symbol)?))
+        })
+    }
+
+    /// Removes all strings from this interner, invalidating all symbols.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.ends.clear();
+    }
+}
+
+impl<const BYTES: usize, const SYMBOLS: usize> Default for Interner<BYTES, SYMBOLS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
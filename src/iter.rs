@@ -0,0 +1,279 @@
+use core::iter::FusedIterator;
+
+use crate::{PushArray, Result};
+
+/// Extension methods for iterators that produce or collect into [`PushArray`]s.
+pub trait PushyIteratorExt: Iterator + Sized {
+    /// Groups the elements of this iterator into [`PushArray`]s of `CAP` elements.
+    ///
+    /// Every chunk is full except possibly the last one.
+    ///
+    /// ```
+    /// use pushy::PushyIteratorExt;
+    ///
+    /// let mut chunks = (1..=5).push_chunks::<2>();
+    ///
+    /// assert_eq!(chunks.next().unwrap(), [1, 2]);
+    /// assert_eq!(chunks.next().unwrap(), [3, 4]);
+    /// assert_eq!(chunks.next().unwrap(), [5]);
+    /// assert!(chunks.next().is_none());
+    /// ```
+    fn push_chunks<const CAP: usize>(self) -> PushChunks<Self, CAP> {
+        PushChunks { iter: self }
+    }
+
+    /// Collects this iterator into a [`PushArray`], failing if it yields more
+    /// than `CAP` elements.
+    ///
+    /// The iterator is consumed up to the first element that doesn't fit,
+    /// which is dropped along with the elements collected so far.
+    ///
+    /// ```
+    /// use pushy::PushyIteratorExt;
+    ///
+    /// let evens = (1..=8).filter(|n| n % 2 == 0).try_collect_pushy::<4>().unwrap();
+    /// assert_eq!(evens, [2, 4, 6, 8]);
+    ///
+    /// assert!((1..=8).try_collect_pushy::<4>().is_err());
+    /// ```
+    fn try_collect_pushy<const CAP: usize>(self) -> Result<PushArray<Self::Item, CAP>> {
+        PushArray::try_from_iter(self)
+    }
+
+    /// Collects this iterator into a [`PushArray`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `CAP` elements.
+    ///
+    /// Not available with the `no-panic-api` feature, see
+    /// [`try_collect_pushy`](Self::try_collect_pushy) instead.
+    ///
+    /// ```
+    /// use pushy::PushyIteratorExt;
+    ///
+    /// let squares = (1..=3).map(|n| n * n).collect_pushy::<8>();
+    /// assert_eq!(squares, [1, 4, 9]);
+    /// ```
+    #[cfg(not(feature = "no-panic-api"))]
+    fn collect_pushy<const CAP: usize>(self) -> PushArray<Self::Item, CAP> {
+        self.collect()
+    }
+}
+
+impl<I: Iterator> PushyIteratorExt for I {}
+
+/// An iterator over [`PushArray`] chunks of another iterator.
+///
+/// Created by [`PushyIteratorExt::push_chunks`].
+#[derive(Debug, Clone)]
+pub struct PushChunks<I, const CAP: usize> {
+    iter: I,
+}
+
+impl<I: Iterator, const CAP: usize> Iterator for PushChunks<I, CAP> {
+    type Item = PushArray<I::Item, CAP>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = PushArray::new();
+
+        (chunk.push_iter(&mut self.iter) > 0).then_some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if CAP == 0 {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iter.size_hint();
+
+        (lower.div_ceil(CAP), upper.map(|upper| upper.div_ceil(CAP)))
+    }
+}
+
+impl<I: FusedIterator, const CAP: usize> FusedIterator for PushChunks<I, CAP> {}
+
+struct AssertNonZero<const N: usize>;
+
+impl<const N: usize> AssertNonZero<N> {
+    const OK: () = assert!(N != 0, "the window or chunk size must be non-zero");
+}
+
+/// An iterator over overlapping array windows of a slice.
+///
+/// Created by [`PushArray::array_windows`].
+#[derive(Debug, Clone)]
+pub struct ArrayWindows<'a, T, const N: usize> {
+    slice: &'a [T],
+}
+
+impl<'a, T, const N: usize> ArrayWindows<'a, T, N> {
+    pub(crate) fn new(slice: &'a [T]) -> Self {
+        let () = AssertNonZero::<N>::OK;
+
+        Self { slice }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayWindows<'a, T, N> {
+    type Item = &'a [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.slice.get(..N)?.try_into().ok();
+        self.slice = &self.slice[1..];
+
+        window
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayWindows<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let start = self.slice.len().checked_sub(N)?;
+        let window = self.slice[start..].try_into().ok();
+        self.slice = &self.slice[..self.slice.len() - 1];
+
+        window
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayWindows<'_, T, N> {
+    fn len(&self) -> usize {
+        (self.slice.len() + 1).saturating_sub(N)
+    }
+}
+
+impl<T, const N: usize> FusedIterator for ArrayWindows<'_, T, N> {}
+
+/// An iterator over windows of a slice that start a fixed hop apart, and may
+/// overlap.
+///
+/// Created by [`PushArray::frames`].
+#[derive(Debug, Clone)]
+pub struct Frames<'a, T> {
+    slice: &'a [T],
+    window_len: usize,
+    hop: usize,
+}
+
+impl<'a, T> Frames<'a, T> {
+    pub(crate) fn new(slice: &'a [T], window_len: usize, hop: usize) -> Self {
+        assert!(window_len != 0, "the window length must be non-zero");
+        assert!(hop != 0, "the hop size must be non-zero");
+
+        Self {
+            slice,
+            window_len,
+            hop,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Frames<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.slice.get(..self.window_len)?;
+        self.slice = self.slice.get(self.hop..).unwrap_or_default();
+
+        Some(frame)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for Frames<'_, T> {
+    fn len(&self) -> usize {
+        match self.slice.len().checked_sub(self.window_len) {
+            Some(rest) => rest / self.hop + 1,
+            None => 0,
+        }
+    }
+}
+
+impl<T> FusedIterator for Frames<'_, T> {}
+
+/// An iterator over non-overlapping array chunks of a slice.
+///
+/// Created by [`PushArray::array_chunks`].
+#[derive(Debug, Clone)]
+pub struct ArrayChunks<'a, T, const N: usize> {
+    chunks: core::slice::ChunksExact<'a, T>,
+}
+
+impl<'a, T, const N: usize> ArrayChunks<'a, T, N> {
+    pub(crate) fn new(slice: &'a [T]) -> Self {
+        let () = AssertNonZero::<N>::OK;
+
+        Self {
+            chunks: slice.chunks_exact(N),
+        }
+    }
+
+    /// Returns the elements at the end of the slice that don't make up a
+    /// full chunk.
+    pub fn remainder(&self) -> &'a [T] {
+        self.chunks.remainder()
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayChunks<'a, T, N> {
+    type Item = &'a [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next()?.try_into().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayChunks<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back()?.try_into().ok()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayChunks<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for ArrayChunks<'_, T, N> {}
+
+/// Fallible conversion from an iterator, for containers with a fixed capacity.
+///
+/// This is the fallible counterpart of [`FromIterator`]: instead of panicking
+/// when the iterator yields more than fits, the conversion fails with
+/// [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity). The iterator
+/// is consumed up to the first element that doesn't fit.
+///
+/// It's implemented by the containers of this crate, so generic code can
+/// collect into any of them:
+///
+/// ```
+/// use pushy::{PushArray, PushString, TryFromIterator};
+///
+/// fn parse_fields<C: TryFromIterator<char>>(line: &str) -> pushy::Result<C> {
+///     C::try_from_iter(line.chars().filter(|ch| !ch.is_whitespace()))
+/// }
+///
+/// let name: PushString<8> = parse_fields("a b c").unwrap();
+/// assert_eq!(name, "abc");
+///
+/// let chars: PushArray<char, 2> = parse_fields("d e").unwrap();
+/// assert_eq!(chars, ['d', 'e']);
+///
+/// assert!(parse_fields::<PushString<2>>("f g h").is_err());
+/// ```
+pub trait TryFromIterator<A>: Sized {
+    /// Creates a value from an iterator, failing if its elements don't fit.
+    fn try_from_iter<I: IntoIterator<Item = A>>(iter: I) -> Result<Self>;
+}
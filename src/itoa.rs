@@ -0,0 +1,67 @@
+//! Integer-to-text routines that don't go through `core::fmt`.
+//!
+//! Digits are written from the back of a stack buffer, and the written part is
+//! returned.
+
+/// Enough room for the digits of `u64::MAX`, or the sign and digits of `i64::MIN`
+pub(crate) const MAX_DEC_LEN: usize = 20;
+
+/// Enough room for the hex digits of `u64::MAX`
+pub(crate) const MAX_HEX_LEN: usize = 16;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+macro_rules! impl_unsigned {
+    ($($dec:ident, $hex:ident: $ty:ty),*) => {
+        $(
+            pub(crate) fn $dec(mut value: $ty, buf: &mut [u8; MAX_DEC_LEN]) -> &[u8] {
+                let mut start = buf.len();
+                loop {
+                    start -= 1;
+                    buf[start] = b'0' + (value % 10) as u8;
+                    value /= 10;
+                    if value == 0 {
+                        break;
+                    }
+                }
+
+                &buf[start..]
+            }
+
+            pub(crate) fn $hex(mut value: $ty, buf: &mut [u8; MAX_HEX_LEN]) -> &[u8] {
+                let mut start = buf.len();
+                loop {
+                    start -= 1;
+                    buf[start] = HEX_DIGITS[(value & 0xf) as usize];
+                    value >>= 4;
+                    if value == 0 {
+                        break;
+                    }
+                }
+
+                &buf[start..]
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u32_dec, u32_hex: u32, u64_dec, u64_hex: u64);
+
+macro_rules! impl_signed {
+    ($($dec:ident: $ty:ty => $unsigned_dec:ident),*) => {
+        $(
+            pub(crate) fn $dec(value: $ty, buf: &mut [u8; MAX_DEC_LEN]) -> &[u8] {
+                let digits = $unsigned_dec(value.unsigned_abs(), buf).len();
+                let mut start = buf.len() - digits;
+                if value < 0 {
+                    start -= 1;
+                    buf[start] = b'-';
+                }
+
+                &buf[start..]
+            }
+        )*
+    };
+}
+
+impl_signed!(i32_dec: i32 => u32_dec, i64_dec: i64 => u64_dec);
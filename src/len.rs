@@ -0,0 +1,76 @@
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An unsigned integer type used to store the length of a [`PushArray`](crate::PushArray).
+///
+/// The length type defaults to `usize`, but a smaller one can be picked to make
+/// small arrays more compact: a `PushArray<u8, 16, u8>` is 17 bytes long, while a
+/// `PushArray<u8, 16>` takes 24 bytes on 64-bit targets.
+///
+/// Using a length type that can't represent `CAP` fails to compile.
+///
+/// This trait is sealed and implemented for `u8`, `u16`, `u32` and `usize`.
+pub trait LenType: Copy + sealed::Sealed {
+    /// A length of zero.
+    const ZERO: Self;
+
+    /// The largest length that can be represented by this type.
+    const MAX: usize;
+
+    /// Converts the given length into this type.
+    ///
+    /// The given length must not be greater than [`LenType::MAX`].
+    fn from_usize(len: usize) -> Self;
+
+    /// Converts this length into a `usize`.
+    fn into_usize(self) -> usize;
+}
+
+macro_rules! impl_len_type {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl LenType for $ty {
+                const ZERO: Self = 0;
+
+                const MAX: usize = if <$ty>::BITS >= usize::BITS {
+                    usize::MAX
+                } else {
+                    <$ty>::MAX as usize
+                };
+
+                #[inline(always)]
+                fn from_usize(len: usize) -> Self {
+                    len as $ty
+                }
+
+                #[inline(always)]
+                fn into_usize(self) -> usize {
+                    self as usize
+                }
+            }
+        )*
+    };
+}
+
+impl_len_type!(u8, u16, u32, usize);
+
+/// Converts the given length into `L` in `const` contexts, where
+/// [`LenType::from_usize`] can't be called.
+///
+/// The given length must not be greater than [`LenType::MAX`].
+pub(crate) const fn len_from_usize<L: LenType>(len: usize) -> L {
+    // Every length type is an unsigned integer no wider than `usize`, so it's
+    // made of the least significant bytes of `len`
+    let bytes = len.to_ne_bytes();
+    let offset = match cfg!(target_endian = "little") {
+        true => 0,
+        false => size_of::<usize>() - size_of::<L>(),
+    };
+
+    // Safety: `L` is an integer, so any bytes are a valid `L`, and it's read
+    //         from within `bytes` since it's no wider than `usize`
+    unsafe { bytes.as_ptr().add(offset).cast::<L>().read_unaligned() }
+}
@@ -1,24 +1,274 @@
 #![no_std]
+#![cfg_attr(feature = "generic_const_exprs", feature(generic_const_exprs))]
+#![cfg_attr(feature = "generic_const_exprs", allow(incomplete_features))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod align;
+mod arena;
+mod cobs;
+#[cfg(feature = "crc")]
+mod crc;
+mod cursor;
+mod double_buffer;
+mod emplace;
+#[cfg(feature = "generic_const_exprs")]
+mod exact;
+mod external_fill;
+mod framer;
+mod ftoa;
+mod gap_buffer;
+mod heap;
+mod index_map;
+mod interner;
+mod iter;
+mod itoa;
+mod len;
+mod lossy;
+mod lru;
+mod min_max_heap;
+pub mod policy;
+mod raw;
+mod slab;
+mod slice;
+pub mod sorted_map;
+mod string;
 mod trait_impls;
+mod transaction;
+mod utf8;
+mod wstring;
+
+use core::{
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr::{addr_of, addr_of_mut},
+};
 
-use core::{mem::MaybeUninit, ptr::addr_of_mut};
+pub use align::AlignedPushArray;
+pub use arena::{GenArena, GenKey};
+pub use cursor::Cursor;
+pub use double_buffer::DoubleBuffer;
+pub use emplace::Emplace;
+pub use external_fill::ExternalFill;
+pub use framer::Framer;
+pub use gap_buffer::GapBuffer;
+pub use heap::PeekHeapMut;
+pub use index_map::FixedIndexMap;
+pub use interner::{Interner, Symbol};
+pub use iter::{ArrayChunks, ArrayWindows, Frames, PushChunks, PushyIteratorExt, TryFromIterator};
+pub use len::LenType;
+pub use lossy::LossyWriter;
+pub use lru::LruCache;
+pub use min_max_heap::MinMaxHeap;
+#[cfg(feature = "derive")]
+pub use pushy_derive::PushSoA;
+pub use slab::PushSlab;
+pub use slice::PushSlice;
+pub use sorted_map::SortedMap;
+pub use string::{IntoChars, PushString};
+pub use transaction::Transaction;
+pub use utf8::Utf8Decoder;
+pub use wstring::PushWString;
 
+/// The errors returned by the fallible operations of this crate.
+///
+/// More variants may be added in the future, so matching on it requires a
+/// wildcard arm.
+///
+/// ```compile_fail,E0004
+/// fn describe(error: pushy::Error) -> &'static str {
+///     match error {
+///         pushy::Error::NotEnoughCapacity => "full",
+///         pushy::Error::NotEnoughElements => "empty",
+///     }
+/// }
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     NotEnoughCapacity,
+    NotEnoughElements,
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// The error returned by pushes that give the rejected value back when there
+/// isn't enough capacity for it, such as [`PushArray::try_push`].
+///
+/// Converts into [`Error::NotEnoughCapacity`], so it can be propagated with
+/// `?` where the value isn't needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T> {
+    value: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Returns a reference to the value that could not be pushed.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value that could not be pushed.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> From<CapacityError<T>> for Error {
+    fn from(_: CapacityError<T>) -> Self {
+        Error::NotEnoughCapacity
+    }
+}
+
 /// A Vec-like (but non-growing) stack-allocated array.
+///
+/// The amount of initialized elements is stored as an `L`, which defaults to
+/// `usize`. See [`LenType`] for using a smaller length field.
+///
+/// # Zero-sized types
+///
+/// Zero-sized elements, such as `()`, are fully supported. A
+/// `PushArray<(), CAP>` takes no more space than a `PushArray` with no
+/// capacity at all, and behaves like a counter that never goes above `CAP`:
+///
+/// * every push, pop and bulk operation updates the length exactly as it would
+///   for any other element, and fails for lack of capacity the same way;
+///
+/// * iteration yields one element per unit of length;
+///
+/// * each element that is removed without being returned is dropped, so
+///   `Drop` implementations on zero-sized types run once per element.
+///
+/// ```
+/// # use pushy::PushArray;
+/// let mut tokens: PushArray<(), 3> = PushArray::new();
+/// tokens.push_array([(), ()]).unwrap();
+///
+/// assert_eq!(tokens.len(), 2);
+/// assert_eq!(tokens.iter().count(), 2);
+/// assert!(tokens.push_array([(), ()]).is_err());
+/// assert_eq!(
+///     core::mem::size_of_val(&tokens),
+///     core::mem::size_of::<PushArray<u64, 0>>()
+/// );
+/// ```
+///
+/// # Layout
+///
+/// [`PushArray`] is `repr(C)`, so it can be embedded in structs shared with C
+/// code. It is laid out as:
+///
+/// * `CAP` elements of `T`, at offset [`DATA_OFFSET`](Self::DATA_OFFSET), i.e.
+///   at the start of the struct. Only the first `len` are initialized.
+///
+/// * The length, an `L`, at offset [`LEN_OFFSET`](Self::LEN_OFFSET).
+///
+/// * With the `stats` feature only, the high-water mark, another `L`.
+///
+/// which corresponds to the following C struct, for `T = uint8_t`,
+/// `CAP = 64` and `L = u16`:
+///
+/// ```c
+/// struct push_array {
+///     uint8_t data[64];
+///     uint16_t len;
+/// };
+/// ```
+///
+/// **The `stats` feature changes this layout**: the extra field makes every
+/// [`PushArray`] larger, so it no longer matches the C struct above. As Cargo
+/// features are unified across the dependency graph, any crate enabling
+/// `stats` changes it for everyone. Code sharing arrays with C should check
+/// [`SIZE`](Self::SIZE) at compile time, so that such a build fails instead of
+/// misbehaving.
 // #[derive(Hash)]
-pub struct PushArray<T, const CAP: usize> {
+#[repr(C)]
+pub struct PushArray<T, const CAP: usize, L: LenType = usize> {
     buf: [MaybeUninit<T>; CAP],
-    len: usize,
+    len: L,
+    /// The greatest length reached before the current one
+    #[cfg(feature = "stats")]
+    high_water_mark: L,
 }
 
 impl<T, const CAP: usize> PushArray<T, CAP> {
+    /// Creates a [`PushArray`] from a backing array and the amount of elements
+    /// initialized in it.
+    ///
+    /// This is the inverse of [`PushArray::into_raw_parts`]. See
+    /// [`PushArray::from_raw_parts_with_len_type`] for arrays with a length
+    /// type other than `usize`.
+    ///
+    /// # Safety
+    ///
+    /// * `len` must be less than or equal to `CAP`.
+    ///
+    /// * The first `len` elements of `buf` must be initialized.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let mut buf = [const { MaybeUninit::uninit() }; 4];
+    /// buf[0].write(10_u32);
+    /// buf[1].write(20);
+    ///
+    /// // Safety: the first two elements were just initialized
+    /// let arr = unsafe { PushArray::from_raw_parts(buf, 2) };
+    ///
+    /// assert_eq!(arr.as_slice(), &[10, 20]);
+    /// ```
+    pub const unsafe fn from_raw_parts(buf: [MaybeUninit<T>; CAP], len: usize) -> Self {
+        // Safety: the caller upholds the same contract
+        unsafe { Self::from_raw_parts_with_len_type(buf, len) }
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Returns a pointer to the buffer along with the length of this
+    /// [`PushArray`], for handing both to the [`raw`] core.
+    #[inline]
+    pub(crate) fn as_mut_ptr_with_len(&mut self) -> (*mut T, &mut L) {
+        let ptr = self.as_mut_ptr();
+
+        (ptr, &mut self.len)
+    }
+
+    /// The offset, in bytes, of the elements within a [`PushArray`].
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// assert_eq!(PushArray::<u32, 8, u16>::DATA_OFFSET, 0);
+    /// ```
+    pub const DATA_OFFSET: usize = core::mem::offset_of!(Self, buf);
+
+    /// The offset, in bytes, of the length within a [`PushArray`].
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// assert_eq!(PushArray::<u32, 8, u16>::LEN_OFFSET, 32);
+    /// ```
+    pub const LEN_OFFSET: usize = core::mem::offset_of!(Self, len);
+
+    /// The size, in bytes, of a [`PushArray`], which includes the high-water
+    /// mark with the `stats` feature.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// // sizeof(struct push_array) on the C side
+    /// const C_SIZE: usize = 66;
+    ///
+    /// # #[cfg(not(feature = "stats"))]
+    /// const _: () = assert!(PushArray::<u8, 64, u16>::SIZE == C_SIZE);
+    /// ```
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Compile-time check that `CAP` can be represented by the length type.
+    const CAP_FITS_LEN: () = assert!(
+        CAP <= L::MAX,
+        "the capacity of this PushArray does not fit in its length type"
+    );
+
     #[inline]
     const fn array_of_uninit() -> [MaybeUninit<T>; CAP] {
         // Safety: safe since this is an array of `MaybeUninit`s and they don't require initialization
@@ -34,14 +284,160 @@ impl<T, const CAP: usize> PushArray<T, CAP> {
     /// assert_eq!(arr.len(), 0);
     /// assert_eq!(arr.initialized(), &[]);
     /// ```
+    ///
+    /// A smaller length type can be used for compact arrays:
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let compact: PushArray<u8, 16, u8> = PushArray::new();
+    /// let default: PushArray<u8, 16> = PushArray::new();
+    ///
+    /// assert!(core::mem::size_of_val(&compact) < core::mem::size_of_val(&default));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use pushy::PushArray;
+    /// // 256 elements can't be counted with a `u8`
+    /// let arr: PushArray<u8, 256, u8> = PushArray::new();
+    /// ```
     pub const fn new() -> Self {
+        let () = Self::CAP_FITS_LEN;
         let buf = Self::array_of_uninit();
 
-        Self { buf, len: 0 }
+        Self {
+            buf,
+            len: L::ZERO,
+            #[cfg(feature = "stats")]
+            high_water_mark: L::ZERO,
+        }
+    }
+
+    /// Creates an empty [`PushArray`] directly on the heap.
+    ///
+    /// Unlike `Box::new(PushArray::new())`, this never places the array on the
+    /// stack first, so it's suitable for capacities larger than the stack.
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut scratch = PushArray::<u8, { 1 << 20 }>::new_boxed();
+    /// scratch.push_str("header").unwrap();
+    ///
+    /// assert_eq!(scratch.len(), 6);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn new_boxed() -> alloc::boxed::Box<Self> {
+        let () = Self::CAP_FITS_LEN;
+        let mut boxed = alloc::boxed::Box::<Self>::new_uninit();
+        let ptr = boxed.as_mut_ptr();
+
+        // Safety: the buffer is made of `MaybeUninit`s, which don't require
+        //         initialization, so writing the length fields is enough
+        unsafe {
+            core::ptr::addr_of_mut!((*ptr).len).write(L::ZERO);
+            #[cfg(feature = "stats")]
+            core::ptr::addr_of_mut!((*ptr).high_water_mark).write(L::ZERO);
+
+            boxed.assume_init()
+        }
+    }
+
+    /// Creates a [`PushArray`] with any [`LenType`] from a backing array and
+    /// the amount of elements initialized in it.
+    ///
+    /// Same as [`PushArray::from_raw_parts`], which only builds arrays with the
+    /// default length type so that it can be inferred.
+    ///
+    /// # Safety
+    ///
+    /// See [`PushArray::from_raw_parts`].
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut arr: PushArray<u8, 4, u8> = PushArray::new();
+    /// arr.push_str("hi").unwrap();
+    /// let (buf, len) = arr.into_raw_parts();
+    ///
+    /// // Safety: `buf` and `len` come straight from `into_raw_parts`
+    /// let arr = unsafe { PushArray::<_, 4, u8>::from_raw_parts_with_len_type(buf, len) };
+    /// assert_eq!(arr.as_str(), Some("hi"));
+    /// ```
+    pub const unsafe fn from_raw_parts_with_len_type(
+        buf: [MaybeUninit<T>; CAP],
+        len: usize,
+    ) -> Self {
+        let () = Self::CAP_FITS_LEN;
+        debug_assert!(
+            len <= CAP,
+            "PushArray::from_raw_parts: len is greater than CAP"
+        );
+
+        Self {
+            buf,
+            len: len::len_from_usize(len),
+            #[cfg(feature = "stats")]
+            high_water_mark: len::len_from_usize(len),
+        }
+    }
+
+    /// Decomposes this [`PushArray`] into its backing array and the amount of
+    /// elements initialized in it.
+    ///
+    /// The initialized elements are not dropped: ownership of them is passed on
+    /// to the caller, who may rebuild the [`PushArray`] with
+    /// [`PushArray::from_raw_parts`].
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut arr: PushArray<u8, 4> = PushArray::new();
+    /// arr.push_str("hi").unwrap();
+    ///
+    /// let (buf, len) = arr.into_raw_parts();
+    /// assert_eq!(len, 2);
+    ///
+    /// // Safety: `buf` and `len` come straight from `into_raw_parts`
+    /// let arr = unsafe { PushArray::from_raw_parts(buf, len) };
+    /// assert_eq!(arr.as_str(), Some("hi"));
+    /// ```
+    pub fn into_raw_parts(self) -> ([MaybeUninit<T>; CAP], usize) {
+        // We're handing the elements over to the caller, so they must not be dropped here
+        let this = ManuallyDrop::new(self);
+
+        // Safety: `this` is never used or dropped after its buffer is read out
+        let buf = unsafe { core::ptr::read(&this.buf) };
+
+        (buf, this.len())
+    }
+
+    /// Creates a [`PushArray`] from an array of possibly uninitialized
+    /// elements, of which the first `len` are initialized.
+    ///
+    /// An alias of [`PushArray::from_raw_parts_with_len_type`], for code that
+    /// thinks of the buffer as uninitialized memory rather than as parts.
+    ///
+    /// # Safety
+    ///
+    /// See [`PushArray::from_raw_parts`].
+    #[inline]
+    pub const unsafe fn from_uninit(buf: [MaybeUninit<T>; CAP], len: usize) -> Self {
+        // Safety: the caller upholds the same contract
+        unsafe { Self::from_raw_parts_with_len_type(buf, len) }
+    }
+
+    /// Converts this [`PushArray`] into an array of possibly uninitialized
+    /// elements, of which the first `len` are initialized.
+    ///
+    /// An alias of [`PushArray::into_raw_parts`], the inverse of
+    /// [`PushArray::from_uninit`].
+    #[inline]
+    pub fn into_uninit(self) -> ([MaybeUninit<T>; CAP], usize) {
+        self.into_raw_parts()
     }
 
     /// Returns the amount of initialized elements in this [`PushArray`].
     /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
     /// # use pushy::PushArray;
     /// let mut arr: PushArray<u32, 5> = PushArray::new();
     /// assert_eq!(arr.len(), 0);
@@ -49,14 +445,16 @@ impl<T, const CAP: usize> PushArray<T, CAP> {
     /// arr.push(0);
     ///
     /// assert_eq!(arr.len(), 1);
+    /// # }
     /// ```
     pub fn len(&self) -> usize {
-        self.len
+        self.len.into_usize()
     }
 
     /// Returns true if this [`PushArray`] is empty.
     ///
     /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
     /// # use pushy::PushArray;
     /// let mut arr: PushArray<u32, 5> = PushArray::new();
     /// assert!(arr.is_empty());
@@ -64,9 +462,10 @@ impl<T, const CAP: usize> PushArray<T, CAP> {
     /// arr.push(0);
     ///
     /// assert_eq!(arr.is_empty(), false);
+    /// # }
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.len == 0
+        self.len() == 0
     }
 
     /// Returns a reference to an initialized element of the array.
@@ -89,7 +488,7 @@ impl<T, const CAP: usize> PushArray<T, CAP> {
         // element in the given index is in-bounds and initialized
         let get_elem = || unsafe { self.get_unchecked(index) };
 
-        (self.len > index).then(get_elem)
+        (self.len() > index).then(get_elem)
     }
 
     /// Returns a mutable reference to an initialized element of the array.
@@ -98,7 +497,41 @@ impl<T, const CAP: usize> PushArray<T, CAP> {
     pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
         // Safety: only called after we've made sure that the
         // element in the given index is in-bounds and initialized
-        (self.len > index).then(|| unsafe { self.get_unchecked_mut(index) })
+        (self.len() > index).then(|| unsafe { self.get_unchecked_mut(index) })
+    }
+
+    /// Returns mutable references to many initialized elements at once.
+    ///
+    /// Returns `None` if any of the given indices is out-of-bounds or not
+    /// initialized, or if the same index was given more than once.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut arr: PushArray<u32, 5> = PushArray::new();
+    /// arr.push_array([1, 2, 3]).unwrap();
+    ///
+    /// if let Some([a, c]) = arr.get_disjoint_mut([0, 2]) {
+    ///     core::mem::swap(a, c);
+    /// }
+    /// assert_eq!(arr.as_slice(), &[3, 2, 1]);
+    ///
+    /// // Overlapping indices are rejected
+    /// assert!(arr.get_disjoint_mut([1, 1]).is_none());
+    /// // So are uninitialized ones
+    /// assert!(arr.get_disjoint_mut([0, 3]).is_none());
+    /// ```
+    pub fn get_disjoint_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.len() || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        let ptr = self.initialized_mut().as_mut_ptr();
+
+        // Safety: every index was checked above to be initialized and
+        // distinct from all others, so the references never alias
+        Some(indices.map(|index| unsafe { &mut *ptr.add(index) }))
     }
 
     /// Returns a reference to an element without doing bounds
@@ -137,19 +570,48 @@ impl<T, const CAP: usize> PushArray<T, CAP> {
         self.buf.get_unchecked_mut(index).assume_init_mut()
     }
 
+    /// Makes sure that the next `additional` pushes into this [`PushArray`]
+    /// will succeed.
+    ///
+    /// Checking once up front allows hot loops to use
+    /// [`push_unchecked`](PushArray::push_unchecked) safely.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let mut arr: PushArray<u16, 4> = PushArray::new();
+    /// arr.push(1);
+    ///
+    /// assert!(arr.ensure_capacity_for(4).is_err());
+    ///
+    /// arr.ensure_capacity_for(3).unwrap();
+    /// for sample in [2, 3, 4] {
+    ///     // Safety: we've just made sure there's room for three elements
+    ///     unsafe { arr.push_unchecked(sample) };
+    /// }
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3, 4]);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn ensure_capacity_for(&self, additional: usize) -> Result<()> {
+        raw::check_capacity(CAP, self.len(), additional)
+    }
+
     /// Pushes an element to the back of the [`PushArray`] without
     /// checking the boundaries of the array first.
     ///
     /// # Safety
     ///
     /// The programmer must ensure this function does not push data after the end of the buffer, which would cause undefined behavior.
+    ///
+    /// This is checked with a debug assertion, so debug builds panic instead.
     pub unsafe fn push_unchecked(&mut self, value: T) {
-        let ptr = self.buf.as_mut_ptr();
-        addr_of_mut!(*ptr)
-            .add(self.len)
-            .write(MaybeUninit::new(value));
+        let len = self.len();
+        debug_assert!(len < CAP, "PushArray::push_unchecked: no capacity left");
+        self.as_mut_ptr().add(len).write(value);
 
-        self.len += 1;
+        self.set_len(len + 1);
     }
 
     /// Push an element to the end of this array after making sure
@@ -165,10 +627,98 @@ impl<T, const CAP: usize> PushArray<T, CAP> {
     /// // Not enough capacity!
     /// assert!(arr.push_checked(9).is_err());
     /// ```
+    #[cfg_attr(
+        feature = "no-panic-api",
+        doc = r#"
+With the `no-panic-api` feature, this replaces [`push`](PushArray::push),
+which is removed:
+
+```compile_fail,E0599
+# use pushy::PushArray;
+let mut arr: PushArray<u32, 2> = PushArray::new();
+arr.push(5);
+```"#
+    )]
+    #[inline]
     pub fn push_checked(&mut self, value: T) -> Result<()> {
-        (self.len < CAP)
-            .then(|| unsafe { self.push_unchecked(value) })
-            .ok_or(Error::NotEnoughCapacity)
+        self.ensure_capacity_for(1)?;
+
+        // Safety: we've just checked that there's room for one more element
+        unsafe { self.push_unchecked(value) };
+
+        Ok(())
+    }
+
+    /// Push an element to the end of this array, giving it back if there's
+    /// no room for it.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut arr: PushArray<String, 1> = PushArray::new();
+    /// arr.try_push("kept".to_string()).unwrap();
+    ///
+    /// let rejected = arr.try_push("returned".to_string()).unwrap_err();
+    /// assert_eq!(rejected.into_inner(), "returned");
+    /// ```
+    pub fn try_push(&mut self, value: T) -> core::result::Result<(), CapacityError<T>> {
+        if self.is_fully_initialized() {
+            return Err(CapacityError { value });
+        }
+
+        // Safety: we've just checked that there's room for one more element
+        unsafe { self.push_unchecked(value) };
+
+        Ok(())
+    }
+
+    /// Push an element to the end of this array, calling `handler` whenever
+    /// there's no room for it.
+    ///
+    /// The handler gets the rejected element and this array, so it can make
+    /// room (by flushing or evicting elements), log the overflow, and so on.
+    /// If it returns true, the push is retried, and the handler called again
+    /// if it fails. If it returns false, the element is given back.
+    ///
+    /// A handler that keeps returning true without making room loops forever.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut sent = Vec::new();
+    /// let mut batch: PushArray<u32, 3> = PushArray::new();
+    ///
+    /// for sample in 1..=7 {
+    ///     batch
+    ///         .push_or_else(sample, |_, batch| {
+    ///             sent.push(batch.as_slice().to_vec());
+    ///             batch.clear();
+    ///             true
+    ///         })
+    ///         .unwrap();
+    /// }
+    ///
+    /// assert_eq!(sent, [[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(batch, [7]);
+    /// ```
+    pub fn push_or_else<F>(
+        &mut self,
+        value: T,
+        mut handler: F,
+    ) -> core::result::Result<(), CapacityError<T>>
+    where
+        F: FnMut(&T, &mut Self) -> bool,
+    {
+        let mut value = value;
+        loop {
+            match self.try_push(value) {
+                Ok(()) => return Ok(()),
+                Err(rejected) => {
+                    if !handler(&rejected.value, self) {
+                        return Err(rejected);
+                    }
+                    value = rejected.value;
+                }
+            }
+        }
     }
 
     /// Push an element to the back of this [`PushArray`].
@@ -177,6 +727,9 @@ impl<T, const CAP: usize> PushArray<T, CAP> {
     ///
     /// Panics if the capacity of this array is overrun.
     ///
+    /// Not available with the `no-panic-api` feature, see
+    /// [`push_checked`](PushArray::push_checked) instead.
+    ///
     /// ```
     /// # use pushy::PushArray;
     /// let mut bytes: PushArray<u8, 2> = PushArray::new();
@@ -185,216 +738,1300 @@ impl<T, const CAP: usize> PushArray<T, CAP> {
     ///
     /// assert_eq!(bytes.as_str().unwrap(), "Hi");
     /// ```
+    #[cfg(not(feature = "no-panic-api"))]
+    #[inline]
     pub fn push(&mut self, value: T) {
-        self.push_checked(value).expect("overflow in PushArray!")
+        if self.is_fully_initialized() {
+            capacity_overflow();
+        }
+
+        // Safety: we've just checked that there's room for one more element
+        unsafe { self.push_unchecked(value) };
     }
 
     /// Push all elements of the given array at the end of the [`PushArray`].
     pub fn push_array<const M: usize>(&mut self, array: [T; M]) -> Result<()> {
-        if self.len + M > CAP {
-            return Err(Error::NotEnoughCapacity);
-        }
+        self.ensure_capacity_for(M)?;
 
+        let len = self.len();
         unsafe {
             // Safety: we've just checked that there is enough capacity to
             // push these elements into our array.
-            (self.as_mut_ptr().add(self.len) as *mut [T; M]).write(array);
+            self.as_mut_ptr().add(len).cast::<[T; M]>().write(array);
+            self.set_len(len + M);
         }
 
-        self.len += M;
-
         Ok(())
     }
 
-    /// Removes the last element from the `PushArray`.
-    pub fn pop(&mut self) -> Option<T> {
-        self.len = self.len.checked_sub(1)?;
+    /// Push all elements of the given array at the end of the [`PushArray`],
+    /// giving the array back if they don't all fit.
+    ///
+    /// Unlike [`push_array`](PushArray::push_array), the elements aren't
+    /// dropped on failure.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut arr: PushArray<String, 2> = PushArray::new();
+    ///
+    /// let rejected = arr.try_push_array(["a".to_string(), "b".to_string(), "c".to_string()]);
+    /// let [a, b, _] = rejected.unwrap_err().into_inner();
+    /// arr.try_push_array([a, b]).unwrap();
+    ///
+    /// assert_eq!(arr, ["a", "b"]);
+    /// ```
+    pub fn try_push_array<const M: usize>(
+        &mut self,
+        array: [T; M],
+    ) -> core::result::Result<(), CapacityError<[T; M]>> {
+        if self.ensure_capacity_for(M).is_err() {
+            return Err(CapacityError { value: array });
+        }
 
-        let mut popped = MaybeUninit::uninit();
+        let len = self.len();
+        // Safety: we've just checked that there is enough capacity to
+        //         push these elements into our array
         unsafe {
-            let ptr = self.as_ptr().add(self.len) as *const T;
-            popped.write(ptr.read());
-            // Safety: we've just written to `popped`, therefore we
-            //         can assume it's uninitialized
-            Some(popped.assume_init())
+            self.as_mut_ptr().add(len).cast::<[T; M]>().write(array);
+            self.set_len(len + M);
         }
+
+        Ok(())
     }
 
-    /// Gets a pointer to the first element of the array.
+    /// Pushes as many elements of the given array as fit, returning how many
+    /// were pushed.
     ///
-    /// # Safety
+    /// The elements that don't fit are dropped.
     ///
-    /// * There is no guarantee that the first element pointed to is initialized.
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let mut arr: PushArray<u8, 4> = PushArray::new();
+    /// arr.push(0);
     ///
-    /// * There is no guarantee that the first element exists (if the capacity allocated was zero).
-    pub unsafe fn as_ptr(&self) -> *const T {
-        &self.buf as *const [MaybeUninit<T>] as *const T
+    /// assert_eq!(arr.push_array_truncated([1, 2, 3, 4, 5]), 3);
+    /// assert_eq!(arr, [0, 1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn push_array_truncated<const M: usize>(&mut self, array: [T; M]) -> usize {
+        self.push_iter(array)
     }
 
-    /// Gets a mutable pointer to the first element of the array.
+    /// Push elements from the given iterator until it runs out or this
+    /// [`PushArray`] is full, returning how many elements were pushed.
     ///
-    /// # Safety
+    /// Items are only pulled from the iterator when there's room for them, so
+    /// passing an iterator by reference leaves whatever didn't fit in it.
     ///
-    /// * There is no guarantee that the first element pointed to is initialized.
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut batch: PushArray<u32, 3> = PushArray::new();
+    /// let mut incoming = 1..=5;
     ///
-    /// * There is no guarantee that the first element exists (if the capacity allocated was zero).
-    pub unsafe fn as_mut_ptr(&mut self) -> *mut T {
-        &mut self.buf as *mut [MaybeUninit<T>] as *mut T
+    /// assert_eq!(batch.push_iter(&mut incoming), 3);
+    /// assert_eq!(batch.as_slice(), &[1, 2, 3]);
+    ///
+    /// // The elements that didn't fit are still in the iterator
+    /// assert_eq!(incoming.next(), Some(4));
+    /// ```
+    #[inline]
+    pub fn push_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let (ptr, len) = self.as_mut_ptr_with_len();
+
+        // Safety: the buffer holds `CAP` elements, the first `len` of which
+        //         are initialized
+        unsafe { raw::push_iter(ptr, len, CAP, iter.into_iter()) }
     }
 
-    /// Returns the initialized elements of this [`PushArray`].
+    /// Pushes the values returned by `f` until this [`PushArray`] is full.
     ///
-    /// Alias to [`PushArray::initialized`].
-    pub fn as_slice(&self) -> &[T] {
-        self.initialized()
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let mut frame: PushArray<u16, 4> = PushArray::new();
+    /// frame.push(0xAA);
+    ///
+    /// let mut counter = 0;
+    /// frame.fill_remaining_with(|| {
+    ///     counter += 1;
+    ///     counter
+    /// });
+    ///
+    /// assert_eq!(frame, [0xAA, 1, 2, 3]);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn fill_remaining_with(&mut self, f: impl FnMut() -> T) {
+        self.push_iter(core::iter::repeat_with(f));
     }
 
-    /// Returns the initialized elements of this [`PushArray`].
-    pub fn initialized(&self) -> &[T] {
-        // Safety:
-        //
-        // * The elements given by `self.as_ptr()` are properly aligned since they come from
-        //   an array (and the memory layout of MaybeUninit<T> is the same as the memory layout of T)
-        //
-        // * The slice will be created only with initialized values since we know that `self.len` is
-        //   the amount of properly initialized elements in our array.
-        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len) }
-    }
+    /// Removes the last element from the `PushArray`.
+    pub fn pop(&mut self) -> Option<T> {
+        let new_len = self.len().checked_sub(1)?;
 
-    /// Returns the initialized elements of this [`PushArray`].
-    pub fn initialized_mut(&mut self) -> &mut [T] {
-        // Safety:
-        //
-        // * The elements given by `self.as_mut_ptr()` are properly aligned since they come from
-        //   an array (and the memory layout of MaybeUninit<T> is the same as the memory layout of T)
-        //
-        // * The slice will be created only with initialized values since we know that `self.len` is
-        //   the amount of properly initialized elements in our array.
-        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+        // Safety: the last element is initialized, and we give up ownership of
+        //         it by shrinking our length before moving it out
+        unsafe {
+            self.set_len(new_len);
+
+            Some(self.as_ptr().add(new_len).read())
+        }
     }
 
-    /// Checks if all elements of this [`PushArray`] are initialized.
+    /// Removes the last `M` elements and returns them as an array, or `None`
+    /// if there are fewer than `M` elements.
+    ///
+    /// This is the counterpart of [`push_array`](PushArray::push_array): the
+    /// elements keep their order.
     ///
     /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
     /// # use pushy::PushArray;
-    /// let mut bytes: PushArray<u8, 5> = PushArray::new();
-    /// assert_eq!(bytes.is_fully_initialized(), false);
+    /// let mut stack: PushArray<i32, 8> = PushArray::new();
+    /// stack.push_array([1, 2, 3]).unwrap();
     ///
-    /// bytes.push_str("Hello").unwrap();
+    /// // Pop the operands of a binary operation
+    /// let [lhs, rhs] = stack.pop_array().unwrap();
+    /// stack.push(lhs - rhs);
     ///
-    /// assert!(bytes.is_fully_initialized());
+    /// assert_eq!(stack, [1, -1]);
+    /// assert_eq!(stack.pop_array::<3>(), None);
+    /// # }
     /// ```
-    pub fn is_fully_initialized(&self) -> bool {
-        self.len == CAP
+    pub fn pop_array<const M: usize>(&mut self) -> Option<[T; M]> {
+        let new_len = self.len().checked_sub(M)?;
+
+        // Safety: the last `M` elements are initialized, and we give up
+        //         ownership of them by shrinking our length before moving them out
+        unsafe {
+            self.set_len(new_len);
+
+            Some(self.as_ptr().add(new_len).cast::<[T; M]>().read())
+        }
     }
 
-    /// Converts this [`PushArray<T; N>`](PushArray) into `[T; N]`, if all `N`
-    /// elements allocated are initialized.
+    /// Exchanges the contents of this [`PushArray`] with `other`.
+    ///
+    /// This moves the backing arrays themselves, so it costs the same no matter
+    /// how many elements are initialized. It is named `swap_contents` so that
+    /// it doesn't shadow [`slice::swap`], which stays reachable through `Deref`.
     ///
     /// ```
     /// # use pushy::PushArray;
-    /// let mut bytes: PushArray<i8, 2> = PushArray::new();
-    /// bytes.push(1);
-    /// bytes.push(5);
+    /// let mut front: PushArray<u8, 8> = PushArray::new();
+    /// let mut back: PushArray<u8, 8> = PushArray::new();
+    /// front.push_str("old").unwrap();
+    /// back.push_str("new").unwrap();
     ///
-    /// assert_eq!(bytes.into_array(), Ok([1, 5]));
+    /// front.swap_contents(&mut back);
+    ///
+    /// assert_eq!(front.as_str(), Some("new"));
+    /// assert_eq!(back.as_str(), Some("old"));
     /// ```
-    pub fn into_array(self) -> core::result::Result<[T; CAP], Self> {
-        if self.is_fully_initialized() {
-            // Safety: it's ok to convert this PushArray into an array
-            // since all elements are initialized and the memory layout
-            // of [MaybeUninit<T>; N] and [T; N] is the same
-            let array = unsafe { self.into_array_unchecked() };
-            Ok(array)
-        } else {
-            Err(self)
-        }
+    pub fn swap_contents(&mut self, other: &mut Self) {
+        core::mem::swap(self, other)
     }
 
-    /// Converts this [`PushArray<T; N>`](PushArray) into `[T; N]` without
-    /// checking if all `N` elements are initialized.
+    /// Exchanges the contents of this [`PushArray`] with a [`PushArray`] of a
+    /// different capacity.
     ///
-    /// # Safety
+    /// Returns [`Error::NotEnoughCapacity`] and leaves both arrays untouched if
+    /// either one can't hold the elements of the other.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let mut small: PushArray<u32, 2> = PushArray::new();
+    /// let mut large: PushArray<u32, 4> = PushArray::new();
+    /// small.push(1);
+    /// large.push_array([2, 3]).unwrap();
+    ///
+    /// small.swap_contents_checked(&mut large).unwrap();
+    /// assert_eq!(small.as_slice(), &[2, 3]);
+    /// assert_eq!(large.as_slice(), &[1]);
+    ///
+    /// large.push_array([4, 5]).unwrap();
+    /// assert!(small.swap_contents_checked(&mut large).is_err());
+    /// # }
+    /// ```
+    pub fn swap_contents_checked<const OTHER: usize, L2: LenType>(
+        &mut self,
+        other: &mut PushArray<T, OTHER, L2>,
+    ) -> Result<()> {
+        let (len, other_len) = (self.len(), other.len());
+        if len > OTHER || other_len > CAP {
+            return Err(not_enough_capacity());
+        }
+
+        let common = len.min(other_len);
+
+        // Safety: both arrays have room for each other's elements, as checked above.
+        //         The first `common` elements are initialized in both arrays and get
+        //         swapped in place, while the remaining initialized elements of the longer
+        //         array are moved into the uninitialized slots of the shorter one.
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let other_ptr = other.as_mut_ptr();
+
+            core::ptr::swap_nonoverlapping(ptr, other_ptr, common);
+
+            if len > other_len {
+                core::ptr::copy_nonoverlapping(
+                    ptr.add(common),
+                    other_ptr.add(common),
+                    len - common,
+                );
+            } else {
+                core::ptr::copy_nonoverlapping(
+                    other_ptr.add(common),
+                    ptr.add(common),
+                    other_len - common,
+                );
+            }
+
+            self.set_len(other_len);
+            other.set_len(len);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the last element from the `PushArray` if the given predicate
+    /// returns true for it.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut stack: PushArray<i32, 4> = PushArray::new();
+    /// stack.push_array([1, 2, 3, 4]).unwrap();
+    ///
+    /// let is_even = |x: &mut i32| *x % 2 == 0;
+    ///
+    /// assert_eq!(stack.pop_if(is_even), Some(4));
+    /// assert_eq!(stack.pop_if(is_even), None);
+    /// assert_eq!(stack.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn pop_if(&mut self, predicate: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        let last = self.initialized_mut().last_mut()?;
+
+        if predicate(last) {
+            self.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Removes the last `n` elements from the `PushArray` and returns them, in
+    /// order, as a new [`PushArray`].
+    ///
+    /// Returns `None` and leaves the array untouched if it has less than `n`
+    /// elements.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut frame: PushArray<u8, 8> = PushArray::new();
+    /// frame.push_str("HDRbody").unwrap();
+    ///
+    /// let body = frame.split_tail(4).unwrap();
+    ///
+    /// assert_eq!(frame.as_str(), Some("HDR"));
+    /// assert_eq!(body.as_str(), Some("body"));
+    ///
+    /// assert!(frame.split_tail(4).is_none());
+    /// ```
+    pub fn split_tail(&mut self, n: usize) -> Option<Self> {
+        let new_len = self.len().checked_sub(n)?;
+        let mut tail = Self::new();
+
+        // Safety: the last `n` elements are initialized and get moved into the
+        //         start of `tail`, which has the same capacity as `self`.
+        //         Our length is updated so that these elements are no longer
+        //         considered ours.
+        unsafe {
+            core::ptr::copy_nonoverlapping(self.as_ptr().add(new_len), tail.as_mut_ptr(), n);
+
+            self.set_len(new_len);
+            tail.set_len(n);
+        }
+
+        Some(tail)
+    }
+
+    /// Moves the elements of this [`PushArray`] into two new ones: the first
+    /// with the elements for which `predicate` returns true, and the second
+    /// with the rest. Both keep the original order.
+    ///
+    /// If `predicate` panics, all elements are dropped.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let numbers: PushArray<u32, 8> = (1..=6).collect();
+    ///
+    /// let (even, odd) = numbers.partition(|n| n % 2 == 0);
+    /// assert_eq!(even, [2, 4, 6]);
+    /// assert_eq!(odd, [1, 3, 5]);
+    /// # }
+    /// ```
+    pub fn partition<P>(mut self, mut predicate: P) -> (Self, Self)
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut matched = Self::new();
+        let mut unmatched = Self::new();
+
+        let len = self.len();
+        // Safety: the elements are handed over to `elements` below, so they
+        //         must not be dropped along with `self`
+        let elements = unsafe {
+            self.set_len(0);
+            raw::MoveOut::new(&mut self.buf, 0, len)
+        };
+
+        for elem in elements {
+            let output = match predicate(&elem) {
+                true => &mut matched,
+                false => &mut unmatched,
+            };
+            // Safety: both outputs have the same capacity as `self`, so there's
+            //         room for every element
+            unsafe { output.push_unchecked(elem) };
+        }
+
+        (matched, unmatched)
+    }
+
+    /// Returns a raw pointer to the start of the buffer of this [`PushArray`].
+    ///
+    /// Like [`Vec::as_ptr`], the pointer is valid for reads of the first
+    /// [`len`](PushArray::len) elements, as long as the array is not moved or
+    /// mutated. Use [`spare_capacity_mut`](PushArray::spare_capacity_mut) to get
+    /// at the uninitialized elements past `len`.
+    ///
+    /// [`Vec::as_ptr`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.as_ptr
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut arr: PushArray<u16, 4> = PushArray::new();
+    /// arr.push_array([1, 2]).unwrap();
+    ///
+    /// let ptr = arr.as_ptr();
+    /// // Safety: two elements are initialized
+    /// assert_eq!(unsafe { ptr.add(1).read() }, 2);
+    /// ```
+    pub fn as_ptr(&self) -> *const T {
+        // No reference to the buffer is created along the way, so this pointer
+        // keeps the provenance of the whole buffer
+        addr_of!(self.buf).cast()
+    }
+
+    /// Returns a mutable raw pointer to the start of the buffer of this [`PushArray`].
+    ///
+    /// Like [`Vec::as_mut_ptr`], the pointer is valid for reads and writes of the
+    /// first [`len`](PushArray::len) elements, as long as the array is not moved or
+    /// otherwise accessed. Use [`spare_capacity_mut`](PushArray::spare_capacity_mut)
+    /// to get at the uninitialized elements past `len`.
+    ///
+    /// [`Vec::as_mut_ptr`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.as_mut_ptr
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        addr_of_mut!(self.buf).cast()
+    }
+
+    /// Returns the uninitialized elements past the end of this [`PushArray`].
+    ///
+    /// After writing to them, [`set_len`](PushArray::set_len) can be used to mark
+    /// them as initialized.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let mut arr: PushArray<u32, 4> = PushArray::new();
+    /// arr.push(1);
+    ///
+    /// let spare = arr.spare_capacity_mut();
+    /// assert_eq!(spare.len(), 3);
+    /// spare[0].write(2);
+    /// spare[1].write(3);
+    ///
+    /// // Safety: the next two elements were just initialized
+    /// unsafe { arr.set_len(3) };
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let len = self.len();
+
+        &mut self.buf[len..]
+    }
+
+    /// Returns the initialized elements and the spare capacity past them at
+    /// the same time.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut out: PushArray<u8, 8> = PushArray::new();
+    /// out.copy_from_slice(b"ab").unwrap();
+    ///
+    /// // Repeat the output so far, as an LZ77 back-reference would
+    /// let (written, spare) = out.split_at_spare_mut();
+    /// for (slot, byte) in spare.iter_mut().zip(written.iter()) {
+    ///     slot.write(*byte);
+    /// }
+    ///
+    /// // Safety: the two bytes after the initialized ones were just written
+    /// unsafe { out.set_len(4) };
+    /// assert_eq!(out, *b"abab");
+    /// ```
+    pub fn split_at_spare_mut(&mut self) -> (&mut [T], &mut [MaybeUninit<T>]) {
+        let len = self.len();
+        let (initialized, spare) = self.buf.split_at_mut(len);
+
+        // Safety: the first `len` elements are initialized, and
+        //         `MaybeUninit<T>` has the same layout as `T`
+        let initialized =
+            unsafe { core::slice::from_raw_parts_mut(initialized.as_mut_ptr().cast::<T>(), len) };
+
+        (initialized, spare)
+    }
+
+    /// Writes `value` to the spare slot at `index`, without marking it as
+    /// initialized.
+    ///
+    /// Slots can be written in any order, and then marked as initialized at
+    /// once with [`commit_up_to`](PushArray::commit_up_to). Writing to the same
+    /// slot twice leaks the first value.
+    ///
+    /// Fails with [`Error::NotEnoughCapacity`] if `index` is not a spare slot,
+    /// that is, if it's not within `len..CAP`.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut message: PushArray<&str, 8> = PushArray::new();
+    ///
+    /// // Fragments arriving out of order
+    /// message.write_at(2, "three").unwrap();
+    /// message.write_at(0, "one").unwrap();
+    /// message.write_at(1, "two").unwrap();
+    /// assert!(message.write_at(8, "nine").is_err());
+    ///
+    /// // Safety: the first three slots were just written
+    /// unsafe { message.commit_up_to(3) };
+    /// assert_eq!(message, ["one", "two", "three"]);
+    /// ```
+    pub fn write_at(&mut self, index: usize, value: T) -> Result<()> {
+        if !(self.len()..CAP).contains(&index) {
+            return Err(not_enough_capacity());
+        }
+
+        self.buf[index].write(value);
+
+        Ok(())
+    }
+
+    /// Marks every slot up to `new_len` as initialized.
+    ///
+    /// # Safety
+    ///
+    /// * `new_len` must be within `len..=CAP`.
+    ///
+    /// * Every slot from the current length up to `new_len` must have been
+    ///   written, e.g. through [`write_at`](PushArray::write_at).
+    ///
+    /// The first requirement is checked with a debug assertion, so debug builds
+    /// panic instead.
+    pub unsafe fn commit_up_to(&mut self, new_len: usize) {
+        debug_assert!(
+            new_len >= self.len(),
+            "PushArray::commit_up_to: new_len is smaller than the current length"
+        );
+
+        // Safety: the caller guarantees that the slots up to `new_len` are initialized
+        unsafe { self.set_len(new_len) };
+    }
+
+    /// Sets the amount of initialized elements of this [`PushArray`].
+    ///
+    /// Elements that are no longer considered initialized are not dropped.
+    ///
+    /// # Safety
+    ///
+    /// * `new_len` must be less than or equal to `CAP`.
+    ///
+    /// * The first `new_len` elements must be initialized.
+    ///
+    /// The first requirement is checked with a debug assertion, so debug builds
+    /// panic instead.
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(
+            new_len <= CAP,
+            "PushArray::set_len: new_len is greater than CAP"
+        );
+        // Every write that can lower the length goes through here, and folds
+        // the current length into the mark first. The only other writes,
+        // through `SetLenOnDrop`, only raise it, so a peak is either recorded
+        // by a later call to this method or is still the current length, which
+        // `high_water_mark` takes into account
+        #[cfg(feature = "stats")]
+        if self.len() > self.high_water_mark.into_usize() {
+            self.high_water_mark = self.len;
+        }
+        self.len = L::from_usize(new_len);
+    }
+
+    /// Returns the greatest amount of elements this [`PushArray`] has held.
+    ///
+    /// Useful for right-sizing `CAP` from measurements. Only available with
+    /// the `stats` feature.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut queue: PushArray<u32, 64> = PushArray::new();
+    /// queue.push_checked(1).unwrap();
+    /// queue.push_checked(2).unwrap();
+    /// queue.pop();
+    ///
+    /// assert_eq!(queue.high_water_mark(), 2);
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark.into_usize().max(self.len())
+    }
+
+    /// Resets the high-water mark to the current length.
+    ///
+    /// Only available with the `stats` feature.
+    #[cfg(feature = "stats")]
+    pub fn reset_high_water_mark(&mut self) {
+        self.high_water_mark = self.len;
+    }
+
+    /// Returns the initialized elements of this [`PushArray`].
+    ///
+    /// Alias to [`PushArray::initialized`].
+    pub fn as_slice(&self) -> &[T] {
+        self.initialized()
+    }
+
+    /// Returns the initialized elements of this [`PushArray`].
+    pub fn initialized(&self) -> &[T] {
+        // Safety:
+        //
+        // * The elements given by `self.as_ptr()` are properly aligned since they come from
+        //   an array (and the memory layout of MaybeUninit<T> is the same as the memory layout of T)
+        //
+        // * The slice will be created only with initialized values since we know that `self.len` is
+        //   the amount of properly initialized elements in our array.
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len()) }
+    }
+
+    /// Returns the initialized elements of this [`PushArray`].
+    pub fn initialized_mut(&mut self) -> &mut [T] {
+        // Safety:
+        //
+        // * The elements given by `self.as_mut_ptr()` are properly aligned since they come from
+        //   an array (and the memory layout of MaybeUninit<T> is the same as the memory layout of T)
+        //
+        // * The slice will be created only with initialized values since we know that `self.len` is
+        //   the amount of properly initialized elements in our array.
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len()) }
+    }
+
+    /// Returns the first `M` initialized elements of this [`PushArray`] as an array
+    /// reference.
+    ///
+    /// Returns `None` if less than `M` elements are initialized. Asking for more
+    /// elements than the capacity of the array fails to compile.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut key: PushArray<u8, 32> = PushArray::new();
+    /// key.push_repeat(0xAB, 16).unwrap();
+    ///
+    /// let prefix: &[u8; 4] = key.as_array().unwrap();
+    /// assert_eq!(prefix, &[0xAB; 4]);
+    ///
+    /// assert!(key.as_array::<32>().is_none());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use pushy::PushArray;
+    /// let key: PushArray<u8, 32> = PushArray::new();
+    ///
+    /// // Can never succeed
+    /// key.as_array::<33>();
+    /// ```
+    pub fn as_array<const M: usize>(&self) -> Option<&[T; M]> {
+        let () = AssertFits::<M, CAP>::OK;
+
+        self.initialized().get(..M)?.try_into().ok()
+    }
+
+    /// Returns the first `M` initialized elements of this [`PushArray`] as a
+    /// mutable array reference.
+    ///
+    /// Returns `None` if less than `M` elements are initialized. Asking for more
+    /// elements than the capacity of the array fails to compile.
+    pub fn as_mut_array<const M: usize>(&mut self) -> Option<&mut [T; M]> {
+        let () = AssertFits::<M, CAP>::OK;
+
+        self.initialized_mut().get_mut(..M)?.try_into().ok()
+    }
+
+    /// Returns an iterator over all overlapping windows of `N` initialized
+    /// elements, as array references.
+    ///
+    /// Asking for windows of zero elements fails to compile.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let samples: PushArray<i32, 8> = [1, 4, 9, 16].into_iter().collect();
+    ///
+    /// let deltas: Vec<_> = samples.array_windows().map(|[a, b]| b - a).collect();
+    /// assert_eq!(deltas, [3, 5, 7]);
+    /// # }
+    /// ```
+    pub fn array_windows<const N: usize>(&self) -> ArrayWindows<'_, T, N> {
+        ArrayWindows::new(self.initialized())
+    }
+
+    /// Returns an iterator over non-overlapping chunks of `N` initialized
+    /// elements, as array references.
+    ///
+    /// Elements at the end that don't make up a full chunk are skipped, and
+    /// can be retrieved with [`ArrayChunks::remainder`]. Asking for chunks of
+    /// zero elements fails to compile.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let bytes: PushArray<u8, 8> = [1, 2, 3, 4, 5].into_iter().collect();
+    ///
+    /// let mut pairs = bytes.array_chunks();
+    /// assert_eq!(pairs.next(), Some(&[1, 2]));
+    /// assert_eq!(pairs.next(), Some(&[3, 4]));
+    /// assert_eq!(pairs.next(), None);
+    /// assert_eq!(pairs.remainder(), &[5]);
+    /// # }
+    /// ```
+    pub fn array_chunks<const N: usize>(&self) -> ArrayChunks<'_, T, N> {
+        ArrayChunks::new(self.initialized())
+    }
+
+    /// Returns an iterator over windows of `window_len` initialized elements,
+    /// each starting `hop` elements after the previous one.
+    ///
+    /// Windows overlap when `hop` is less than `window_len`, as in short-time
+    /// Fourier transforms. Elements at the end that don't make up a full
+    /// window are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_len` or `hop` is zero.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let samples: PushArray<i16, 16> = (1..=7).collect();
+    ///
+    /// let energies: Vec<i16> = samples
+    ///     .frames(4, 2)
+    ///     .map(|frame| frame.iter().map(|s| s * s).sum())
+    ///     .collect();
+    ///
+    /// // Frames [1, 2, 3, 4], [3, 4, 5, 6], and [5, 6, 7] is too short
+    /// assert_eq!(energies, [30, 86]);
+    /// # }
+    /// ```
+    pub fn frames(&self, window_len: usize, hop: usize) -> Frames<'_, T> {
+        Frames::new(self.initialized(), window_len, hop)
+    }
+
+    /// Checks if all elements of this [`PushArray`] are initialized.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut bytes: PushArray<u8, 5> = PushArray::new();
+    /// assert_eq!(bytes.is_fully_initialized(), false);
+    ///
+    /// bytes.push_str("Hello").unwrap();
+    ///
+    /// assert!(bytes.is_fully_initialized());
+    /// ```
+    pub fn is_fully_initialized(&self) -> bool {
+        self.len() == CAP
+    }
+
+    /// Converts this [`PushArray<T; N>`](PushArray) into `[T; N]`, if all `N`
+    /// elements allocated are initialized.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let mut bytes: PushArray<i8, 2> = PushArray::new();
+    /// bytes.push(1);
+    /// bytes.push(5);
+    ///
+    /// assert_eq!(bytes.into_array(), Ok([1, 5]));
+    /// # }
+    /// ```
+    pub fn into_array(self) -> core::result::Result<[T; CAP], Self> {
+        if self.is_fully_initialized() {
+            // Safety: it's ok to convert this PushArray into an array
+            // since all elements are initialized and the memory layout
+            // of [MaybeUninit<T>; N] and [T; N] is the same
+            let array = unsafe { self.into_array_unchecked() };
+            Ok(array)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Converts this [`PushArray<T; N>`](PushArray) into `[T; N]` without
+    /// checking if all `N` elements are initialized.
+    ///
+    /// # Safety
     ///
     /// Calling this function with a [`PushArray`] that is not fully initialized
     /// results in unknown behavior.
     ///
     /// ```
     /// # use pushy::PushArray;
-    /// let mut bytes: PushArray<i8, 2> = PushArray::new();
-    /// bytes.push_array([2, 3]).unwrap();
+    /// let mut bytes: PushArray<i8, 2> = PushArray::new();
+    /// bytes.push_array([2, 3]).unwrap();
+    ///
+    /// // Safe to convert to array since all elements are initialized
+    /// let array = unsafe { bytes.into_array_unchecked() };
+    ///
+    /// assert_eq!(array, [2, 3]);
+    /// ```
+    pub unsafe fn into_array_unchecked(self) -> [T; CAP] {
+        debug_assert!(
+            self.is_fully_initialized(),
+            "PushArray::into_array_unchecked: not all elements are initialized"
+        );
+
+        // The elements are moved into the returned array, so they must not be dropped here
+        let this = ManuallyDrop::new(self);
+
+        this.as_ptr().cast::<[T; CAP]>().read()
+    }
+
+    /// Clear the [`PushArray`]. All initialized elements will be dropped.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut bytes: PushArray<u8, 5> = PushArray::new();
+    /// bytes.push_str("Hello").unwrap();
+    ///
+    /// assert_eq!(
+    ///     bytes.as_str().unwrap(),
+    ///     "Hello"
+    /// );
+    ///
+    /// // Logically clear this array
+    /// bytes.clear();
+    ///
+    /// assert_eq!(
+    ///     bytes.as_str().unwrap(),
+    ///     ""
+    /// );
+    /// ```
+    pub fn clear(&mut self) {
+        self.drop_tail(0)
+    }
+
+    /// Drops the elements past the first `new_len`, if any.
+    pub(crate) fn drop_tail(&mut self, new_len: usize) {
+        let len = self.len();
+        if new_len >= len {
+            return;
+        }
+
+        // Safety: the elements past `new_len` are no longer considered
+        //         initialized once the length is lowered, so they are dropped
+        //         exactly once
+        unsafe {
+            self.set_len(new_len);
+            raw::drop_range(&mut self.buf, new_len, len);
+        }
+    }
+}
+
+// The failure paths are kept out of line so that they don't bloat the hot push
+// loops they're called from, and marked cold so that the compiler lays out the
+// happy path first.
+
+#[cold]
+#[inline(never)]
+fn not_enough_capacity() -> Error {
+    Error::NotEnoughCapacity
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[cold]
+#[inline(never)]
+pub(crate) fn capacity_overflow() -> ! {
+    panic!("overflow in PushArray!")
+}
+
+/// Keeps track of a length while elements are written through a raw pointer,
+/// storing it into the length field of a [`PushArray`] when dropped.
+///
+/// Only the length field is borrowed, so pointers into the buffer stay valid.
+///
+/// This is what makes loops that call into user code (`Iterator::next`,
+/// `Clone::clone`, ...) panic-safe: if that code panics, the guard is dropped
+/// during unwinding and the elements written so far become part of the array,
+/// which then drops them normally.
+pub(crate) struct SetLenOnDrop<'a, L: LenType> {
+    len: &'a mut L,
+    local_len: usize,
+}
+
+impl<'a, L: LenType> SetLenOnDrop<'a, L> {
+    #[inline]
+    pub(crate) fn new(len: &'a mut L) -> Self {
+        let local_len = len.into_usize();
+
+        Self { len, local_len }
+    }
+
+    #[inline]
+    pub(crate) fn current_len(&self) -> usize {
+        self.local_len
+    }
+
+    #[inline]
+    pub(crate) fn increment_len(&mut self, increment: usize) {
+        self.local_len += increment;
+    }
+}
+
+impl<L: LenType> Drop for SetLenOnDrop<'_, L> {
+    #[inline]
+    fn drop(&mut self) {
+        *self.len = L::from_usize(self.local_len);
+    }
+}
+
+/// Compile-time check that `M` elements fit into a capacity of `CAP`.
+struct AssertFits<const M: usize, const CAP: usize>;
+
+impl<const M: usize, const CAP: usize> AssertFits<M, CAP> {
+    const OK: () = assert!(M <= CAP, "PushArray does not have enough capacity");
+}
+
+impl<T: Clone, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Push `n` copies of `value` to the back of the [`PushArray`].
+    ///
+    /// Capacity is checked once for all `n` elements: if they don't fit,
+    /// nothing is pushed.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut record: PushArray<u8, 8> = PushArray::new();
+    /// record.push_str("ID").unwrap();
+    ///
+    /// // Pad the record with spaces
+    /// record.push_repeat(b' ', 6).unwrap();
+    /// assert_eq!(record.as_str(), Some("ID      "));
+    ///
+    /// assert!(record.push_repeat(b' ', 1).is_err());
+    /// ```
+    #[inline]
+    pub fn push_repeat(&mut self, value: T, n: usize) -> Result<()> {
+        let (ptr, len) = self.as_mut_ptr_with_len();
+
+        // Safety: the buffer holds `CAP` elements, the first `len` of which
+        //         are initialized
+        unsafe { raw::push_repeat(ptr, len, CAP, value, n) }
+    }
+
+    /// Pushes copies of `value` until this [`PushArray`] is full.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut frame: PushArray<u8, 6> = PushArray::new();
+    /// frame.push_str("OK").unwrap();
+    ///
+    /// // Fixed-size frames are padded with zeroes
+    /// frame.fill_to_capacity(0);
+    /// assert_eq!(frame, *b"OK\0\0\0\0");
+    /// ```
+    pub fn fill_to_capacity(&mut self, value: T) {
+        let remaining = CAP - self.len();
+
+        // Exactly the remaining capacity is asked for, so this can't fail
+        let _ = self.push_repeat(value, remaining);
+    }
+}
+
+impl<T: Copy, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Copy the elements from the given slice into the end of the [`PushArray`].
+    ///
+    // ```
+    // # use pushy::PushArray;
+    // let mut bytes: PushArray<u8, 5> = PushArray::new();
+    // bytes.copy_from_slice(b"Hello").unwrap();
+    //
+    // assert_eq!(bytes.as_str(), Some("Hello"));
+    // ```
+    #[inline]
+    pub fn copy_from_slice(&mut self, slice: &[T]) -> Result<()> {
+        let len = self.len();
+        let new_len = raw::extend_from_slice(&mut self.buf, len, slice)?;
+
+        // Safety: the elements up to `new_len` were just initialized
+        unsafe { self.set_len(new_len) };
+
+        Ok(())
+    }
+
+    /// Copies as many elements of `slice` as fit, returning how many were
+    /// copied.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut log: PushArray<u8, 8> = PushArray::new();
+    /// log.push_str("[W] ").unwrap();
+    ///
+    /// // The message is cut short instead of being dropped altogether
+    /// assert_eq!(log.copy_from_slice_truncated(b"overheating"), 4);
+    /// assert_eq!(log.as_str(), Some("[W] over"));
+    /// ```
+    pub fn copy_from_slice_truncated(&mut self, slice: &[T]) -> usize {
+        let copied = slice.len().min(CAP - self.len());
+
+        // Only as much as there's room for is copied, so this can't fail
+        let _ = self.copy_from_slice(&slice[..copied]);
+
+        copied
+    }
+
+    /// Copies the elements of all the given slices into the end of this
+    /// [`PushArray`], in order.
+    ///
+    /// The total length is checked upfront: if it doesn't fit, nothing is
+    /// pushed and [`Error::NotEnoughCapacity`] is returned.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let header = [0x7e, 0x01];
+    /// let payload = b"ping";
+    /// let crc = [0xab, 0xcd];
     ///
-    /// // Safe to convert to array since all elements are initialized
-    /// let array = unsafe { bytes.into_array_unchecked() };
+    /// let mut frame: PushArray<u8, 8> = PushArray::new();
+    /// frame.push_slices(&[&header, payload, &crc]).unwrap();
+    /// assert_eq!(frame, [0x7e, 0x01, b'p', b'i', b'n', b'g', 0xab, 0xcd]);
     ///
-    /// assert_eq!(array, [2, 3]);
+    /// // Doesn't fit, so nothing gets pushed
+    /// frame.clear();
+    /// assert!(frame.push_slices(&[&header, b"pong!", &crc]).is_err());
+    /// assert!(frame.is_empty());
     /// ```
-    pub unsafe fn into_array_unchecked(self) -> [T; CAP] {
-        let ptr = self.as_ptr() as *const [T; CAP];
-        ptr.read()
+    pub fn push_slices(&mut self, slices: &[&[T]]) -> Result<()> {
+        let total = slices
+            .iter()
+            .try_fold(0usize, |total, slice| total.checked_add(slice.len()))
+            .ok_or(Error::NotEnoughCapacity)?;
+        self.ensure_capacity_for(total)?;
+
+        for slice in slices {
+            // Can't fail, since we've checked the total length above
+            self.copy_from_slice(slice)?;
+        }
+
+        Ok(())
     }
 
-    /// Clear the [`PushArray`]. All initialized elements will be dropped.
+    /// Interleaves the given channels into the end of this [`PushArray`], pushing
+    /// the first element of every channel, then the second one, and so on.
+    ///
+    /// Returns [`Error::NotEnoughElements`] if the channels have different lengths,
+    /// or [`Error::NotEnoughCapacity`] if they don't fit. Nothing is pushed in
+    /// either case.
     ///
     /// ```
     /// # use pushy::PushArray;
-    /// let mut bytes: PushArray<u8, 5> = PushArray::new();
-    /// bytes.push_str("Hello").unwrap();
+    /// let left = [1, 2, 3];
+    /// let right = [-1, -2, -3];
     ///
-    /// assert_eq!(
-    ///     bytes.as_str().unwrap(),
-    ///     "Hello"
-    /// );
+    /// let mut stereo: PushArray<i16, 8> = PushArray::new();
+    /// stereo.push_interleaved(&[&left, &right]).unwrap();
     ///
-    /// // Logically clear this array
-    /// bytes.clear();
+    /// assert_eq!(stereo, [1, -1, 2, -2, 3, -3]);
+    /// ```
+    pub fn push_interleaved<const CHANNELS: usize>(
+        &mut self,
+        channels: &[&[T]; CHANNELS],
+    ) -> Result<()> {
+        let Some(frames) = channels.first().map(|channel| channel.len()) else {
+            return Ok(());
+        };
+        if channels.iter().any(|channel| channel.len() != frames) {
+            return Err(Error::NotEnoughElements);
+        }
+        let total = frames
+            .checked_mul(CHANNELS)
+            .ok_or(Error::NotEnoughCapacity)?;
+        self.ensure_capacity_for(total)?;
+
+        for frame in 0..frames {
+            for channel in channels {
+                // Safety: we've checked that there's room for every element
+                unsafe { self.push_unchecked(channel[frame]) };
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Splits the interleaved elements of this [`PushArray`] into one
+    /// [`PushArray`] per channel, pushing to the end of each of them.
+    ///
+    /// Returns [`Error::NotEnoughElements`] if the length of this array isn't a
+    /// multiple of `CHANNELS`, or [`Error::NotEnoughCapacity`] if any channel
+    /// doesn't have enough room. Nothing is pushed in either case.
     ///
-    /// assert_eq!(
-    ///     bytes.as_str().unwrap(),
-    ///     ""
-    /// );
     /// ```
-    pub fn clear(&mut self) {
-        unsafe {
-            core::ptr::drop_in_place(self.initialized_mut());
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let stereo: PushArray<i16, 8> = [1, -1, 2, -2, 3, -3].into_iter().collect();
+    ///
+    /// let mut planar: [PushArray<i16, 4>; 2] = Default::default();
+    /// stereo.deinterleave_into(&mut planar).unwrap();
+    ///
+    /// assert_eq!(planar[0], [1, 2, 3]);
+    /// assert_eq!(planar[1], [-1, -2, -3]);
+    /// # }
+    /// ```
+    pub fn deinterleave_into<const CHANNELS: usize, const N: usize, L2: LenType>(
+        &self,
+        channels: &mut [PushArray<T, N, L2>; CHANNELS],
+    ) -> Result<()> {
+        if CHANNELS == 0 {
+            return match self.is_empty() {
+                true => Ok(()),
+                false => Err(Error::NotEnoughCapacity),
+            };
+        }
+        if !self.len().is_multiple_of(CHANNELS) {
+            return Err(Error::NotEnoughElements);
+        }
+        let frames = self.len() / CHANNELS;
+        for channel in channels.iter() {
+            channel.ensure_capacity_for(frames)?;
         }
-        self.len = 0;
+
+        for frame in self.chunks_exact(CHANNELS) {
+            for (&sample, channel) in frame.iter().zip(channels.iter_mut()) {
+                // Safety: we've checked that every channel has enough room
+                unsafe { channel.push_unchecked(sample) };
+            }
+        }
+
+        Ok(())
     }
 }
 
-impl<T: Copy, const CAP: usize> PushArray<T, CAP> {
-    /// Copy the elements from the given slice into the end of the [`PushArray`].
+impl<T: Ord, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Merges two sorted [`PushArray`]s into a new sorted one, in a single pass.
     ///
-    // ```
-    // # use pushy::PushArray;
-    // let mut bytes: PushArray<u8, 5> = PushArray::new();
-    // bytes.copy_from_slice(b"Hello").unwrap();
-    //
-    // assert_eq!(bytes.as_str(), Some("Hello"));
-    // ```
-    pub fn copy_from_slice(&mut self, slice: &[T]) -> Result<()> {
-        if self.len + slice.len() > CAP {
-            return Err(Error::NotEnoughCapacity);
+    /// The merge is stable: equal elements of `a` come before those of `b`. If
+    /// the inputs aren't sorted, the output is unspecified, but holds all of
+    /// their elements.
+    ///
+    /// If the inputs don't fit together in the output, they are given back.
+    /// If comparing elements panics, all elements are dropped.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let a: PushArray<u8, 4> = [1, 4, 9].into_iter().collect();
+    /// let b: PushArray<u8, 4> = [2, 3, 10].into_iter().collect();
+    ///
+    /// let merged: PushArray<u8, 6> = PushArray::merge_sorted(a, b).unwrap();
+    /// assert_eq!(merged, [1, 2, 3, 4, 9, 10]);
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn merge_sorted<const A: usize, const B: usize, LA: LenType, LB: LenType>(
+        mut a: PushArray<T, A, LA>,
+        mut b: PushArray<T, B, LB>,
+    ) -> core::result::Result<Self, (PushArray<T, A, LA>, PushArray<T, B, LB>)> {
+        let (a_len, b_len) = (a.len(), b.len());
+        if a_len + b_len > CAP {
+            return Err((a, b));
         }
 
-        // Safety: we've just checked that there is enough storage
-        //         to hold the new elements.
-        //
-        //         We also know these elements are trivially copiable since they implement Copy.
-        unsafe {
-            core::ptr::copy_nonoverlapping(
-                slice.as_ptr(),
-                self.as_mut_ptr().add(self.len),
-                slice.len(),
-            );
+        let mut merged = Self::new();
+        // Safety: the elements are handed over to the cursors below, so they
+        //         must not be dropped along with the inputs
+        let (mut a_elems, mut b_elems) = unsafe {
+            a.set_len(0);
+            b.set_len(0);
+            (
+                raw::MoveOut::new(&mut a.buf, 0, a_len),
+                raw::MoveOut::new(&mut b.buf, 0, b_len),
+            )
+        };
+
+        // The output has room for all of the elements, as checked above
+        while let (Some(x), Some(y)) = (a_elems.peek(), b_elems.peek()) {
+            let source = match y < x {
+                true => &mut b_elems,
+                false => &mut a_elems,
+            };
+            if let Some(elem) = source.next() {
+                // Safety: see above
+                unsafe { merged.push_unchecked(elem) };
+            }
+        }
+        for elem in a_elems.chain(b_elems) {
+            // Safety: see above
+            unsafe { merged.push_unchecked(elem) };
         }
 
-        self.len += slice.len();
-        Ok(())
+        Ok(merged)
+    }
+}
+
+impl<A, B, const CAP: usize, L: LenType> PushArray<(A, B), CAP, L> {
+    /// Splits an array of pairs into an array of first elements and an array of
+    /// second elements.
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no-panic-api"))] {
+    /// # use pushy::PushArray;
+    /// let readings: PushArray<(u32, f32), 4> = [(10, 1.5), (20, 2.5)].into_iter().collect();
+    ///
+    /// let (timestamps, values) = readings.unzip();
+    /// assert_eq!(timestamps, [10, 20]);
+    /// assert_eq!(values, [1.5, 2.5]);
+    /// # }
+    /// ```
+    pub fn unzip(mut self) -> (PushArray<A, CAP, L>, PushArray<B, CAP, L>) {
+        let mut firsts = PushArray::new();
+        let mut seconds = PushArray::new();
+
+        let len = self.len();
+        // Safety: the elements are moved out below, so they must not be dropped
+        //         along with `self`
+        unsafe { self.set_len(0) };
+
+        for idx in 0..len {
+            // Safety: `idx` is below the original length, and every element is
+            //         read exactly once. Both outputs have the same capacity as
+            //         `self`, so there's room for every element.
+            unsafe {
+                let (first, second) = self.as_ptr().add(idx).read();
+                firsts.push_unchecked(first);
+                seconds.push_unchecked(second);
+            }
+        }
+
+        (firsts, seconds)
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> PushArray<MaybeUninit<T>, CAP, L> {
+    /// Creates a full [`PushArray`] of uninitialized slots.
+    ///
+    /// Slots can then be initialized in any order, and the whole array turned
+    /// into a [`PushArray<T>`] with [`assume_init`](PushArray::assume_init).
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let mut slots: PushArray<MaybeUninit<u16>, 3> = PushArray::uninit();
+    /// assert_eq!(slots.len(), 3);
+    ///
+    /// slots[2].write(30);
+    /// slots[0].write(10);
+    /// slots[1].write(20);
+    ///
+    /// // Safety: every slot was just initialized
+    /// let values = unsafe { slots.assume_init() };
+    /// assert_eq!(values, [10, 20, 30]);
+    /// ```
+    pub fn uninit() -> Self {
+        // Safety: `MaybeUninit` needs no initialization, so every slot counts as
+        //         initialized
+        unsafe { Self::from_raw_parts_with_len_type(Self::array_of_uninit(), CAP) }
+    }
+
+    /// Converts this array of possibly uninitialized slots into an array of
+    /// their values, keeping the length.
+    ///
+    /// # Safety
+    ///
+    /// The first `len` slots must be initialized.
+    pub unsafe fn assume_init(self) -> PushArray<T, CAP, L> {
+        let (buf, len) = self.into_raw_parts();
+
+        // Safety: `MaybeUninit<MaybeUninit<T>>` has the same layout as
+        //         `MaybeUninit<T>`, so both arrays have the same layout too
+        let buf = unsafe {
+            core::ptr::read(
+                &buf as *const [MaybeUninit<MaybeUninit<T>>; CAP] as *const [MaybeUninit<T>; CAP],
+            )
+        };
+
+        // Safety: the caller guarantees that the first `len` slots are initialized
+        unsafe { PushArray::from_raw_parts_with_len_type(buf, len) }
+    }
+}
+
+impl<'a, const CAP: usize, L: LenType> PushArray<&'a str, CAP, L> {
+    /// Splits `text` at every occurrence of `delimiter`, collecting the pieces.
+    ///
+    /// Like [`str::split`], consecutive delimiters make for empty pieces.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`] if there are more than `CAP` pieces.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let fields: PushArray<&str, 4> = PushArray::from_split("21.5,40,,1013", ',').unwrap();
+    /// assert_eq!(fields, ["21.5", "40", "", "1013"]);
+    ///
+    /// assert!(PushArray::<&str, 4>::from_split("a,b,c,d,e", ',').is_err());
+    /// ```
+    pub fn from_split(text: &'a str, delimiter: char) -> Result<Self> {
+        Self::from_pieces(text.split(delimiter))
+    }
+
+    /// Splits `text` by whitespace, collecting the pieces.
+    ///
+    /// Like [`str::split_whitespace`], no piece is empty.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`] if there are more than `CAP` pieces.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let line = "  set   led 1 ";
+    /// let args: PushArray<&str, 4> = PushArray::from_split_whitespace(line).unwrap();
+    ///
+    /// assert_eq!(args, ["set", "led", "1"]);
+    /// ```
+    pub fn from_split_whitespace(text: &'a str) -> Result<Self> {
+        Self::from_pieces(text.split_whitespace())
+    }
+
+    fn from_pieces(pieces: impl Iterator<Item = &'a str>) -> Result<Self> {
+        let mut arr = Self::new();
+        for piece in pieces {
+            arr.push_checked(piece)?;
+        }
+
+        Ok(arr)
     }
 }
 
-impl<const CAP: usize> PushArray<u8, CAP> {
+impl<const CAP: usize, L: LenType> PushArray<u8, CAP, L> {
     /// Returns the bytes of this [`PushArray`] as a `&str` if they're valid UTF-8.
     /// ```
     /// # use pushy::PushArray;
@@ -409,6 +2046,55 @@ impl<const CAP: usize> PushArray<u8, CAP> {
         core::str::from_utf8(self.initialized()).ok()
     }
 
+    /// Returns the longest prefix of this [`PushArray`] that is valid UTF-8.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut bytes: PushArray<u8, 8> = PushArray::new();
+    /// bytes.copy_from_slice(b"ol\xc3\xa1\xc3").unwrap();
+    ///
+    /// // The last byte starts a char that isn't complete yet
+    /// assert_eq!(bytes.valid_str(), "olá");
+    /// ```
+    pub fn valid_str(&self) -> &str {
+        match core::str::from_utf8(self) {
+            Ok(valid) => valid,
+            Err(error) => {
+                let valid = &self[..error.valid_up_to()];
+                // Safety: `valid_up_to` is the length of the valid prefix
+                unsafe { core::str::from_utf8_unchecked(valid) }
+            }
+        }
+    }
+
+    /// Returns an iterator over the chars of this [`PushArray`], stopping at
+    /// the first invalid or incomplete UTF-8 sequence.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut bytes: PushArray<u8, 8> = PushArray::new();
+    /// bytes.copy_from_slice(b"h\xc3\xa9\xffllo").unwrap();
+    ///
+    /// assert!(bytes.chars().eq(['h', 'é']));
+    /// ```
+    pub fn chars(&self) -> core::str::Chars<'_> {
+        self.valid_str().chars()
+    }
+
+    /// Returns an iterator over the chars of this [`PushArray`] and their byte
+    /// positions, stopping at the first invalid or incomplete UTF-8 sequence.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut bytes: PushArray<u8, 8> = PushArray::new();
+    /// bytes.push_str("añb").unwrap();
+    ///
+    /// assert!(bytes.char_indices().eq([(0, 'a'), (1, 'ñ'), (3, 'b')]));
+    /// ```
+    pub fn char_indices(&self) -> core::str::CharIndices<'_> {
+        self.valid_str().char_indices()
+    }
+
     /// Push a UTF-8 string to the back of this [`PushArray`].
     ///
     /// ```
@@ -424,4 +2110,306 @@ impl<const CAP: usize> PushArray<u8, CAP> {
 
         self.copy_from_slice(bytes)
     }
+
+    /// Collects chars into a new byte [`PushArray`], encoded as UTF-8.
+    ///
+    /// Fails with [`Error::NotEnoughCapacity`] if they take more than `CAP`
+    /// bytes.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let upper = PushArray::<u8, 4>::try_from_chars("añ".chars().flat_map(char::to_uppercase));
+    /// assert_eq!(upper.unwrap().as_str(), Some("AÑ"));
+    /// ```
+    pub fn try_from_chars<I: IntoIterator<Item = char>>(chars: I) -> Result<Self> {
+        let mut bytes = Self::new();
+        for ch in chars {
+            bytes.push_str(ch.encode_utf8(&mut [0; 4]))?;
+        }
+
+        Ok(bytes)
+    }
+
+    /// Concatenates string slices into a new byte [`PushArray`].
+    ///
+    /// Fails with [`Error::NotEnoughCapacity`] if they take more than `CAP`
+    /// bytes.
+    pub fn try_from_strs<'a, I: IntoIterator<Item = &'a str>>(pieces: I) -> Result<Self> {
+        let mut bytes = Self::new();
+        for piece in pieces {
+            bytes.push_str(piece)?;
+        }
+
+        Ok(bytes)
+    }
+
+    /// Returns the position of the first occurrence of `needle` in this
+    /// [`PushArray`], if any.
+    ///
+    /// An empty `needle` is found at position 0.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut frame: PushArray<u8, 16> = PushArray::new();
+    /// frame.copy_from_slice(&[0x00, 0x7e, 0x7e, 0x01, 0x02]).unwrap();
+    ///
+    /// // Skip the noise before the start-of-frame marker
+    /// assert_eq!(frame.find(&[0x7e, 0x7e]), Some(1));
+    /// assert_eq!(frame.find(&[0x7e, 0x02]), None);
+    /// ```
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+
+        self.windows(needle.len())
+            .position(|window| window == needle)
+    }
+
+    /// Returns true if `needle` occurs in this [`PushArray`].
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut response: PushArray<u8, 16> = PushArray::new();
+    /// response.push_str("+CSQ: 21,0\r\n").unwrap();
+    ///
+    /// assert!(response.contains_subslice(b"\r\n"));
+    /// assert!(!response.contains_subslice(b"ERROR"));
+    /// ```
+    pub fn contains_subslice(&self, needle: &[u8]) -> bool {
+        self.find(needle).is_some()
+    }
+
+    /// Appends a copy of this [`PushArray`] to `out`, with ASCII letters
+    /// converted to uppercase.
+    ///
+    /// Other bytes are copied as they are. If they don't all fit, nothing is
+    /// appended and [`Error::NotEnoughCapacity`] is returned. For in-place
+    /// conversion, see [`make_ascii_uppercase`](slice::make_ascii_uppercase).
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut method: PushArray<u8, 8> = PushArray::new();
+    /// method.push_str("get").unwrap();
+    ///
+    /// let mut request: PushArray<u8, 32> = PushArray::new();
+    /// method.to_ascii_uppercase_into(&mut request).unwrap();
+    ///
+    /// assert_eq!(request.as_str(), Some("GET"));
+    /// ```
+    pub fn to_ascii_uppercase_into<const N: usize, L2: LenType>(
+        &self,
+        out: &mut PushArray<u8, N, L2>,
+    ) -> Result<()> {
+        out.ensure_capacity_for(self.len())?;
+
+        for byte in self.iter() {
+            // Safety: we've just checked that every byte fits
+            unsafe { out.push_unchecked(byte.to_ascii_uppercase()) };
+        }
+
+        Ok(())
+    }
+
+    /// Appends a copy of this [`PushArray`] to `out`, with ASCII letters
+    /// converted to lowercase.
+    ///
+    /// Other bytes are copied as they are. If they don't all fit, nothing is
+    /// appended and [`Error::NotEnoughCapacity`] is returned. For in-place
+    /// conversion, see [`make_ascii_lowercase`](slice::make_ascii_lowercase).
+    pub fn to_ascii_lowercase_into<const N: usize, L2: LenType>(
+        &self,
+        out: &mut PushArray<u8, N, L2>,
+    ) -> Result<()> {
+        out.ensure_capacity_for(self.len())?;
+
+        for byte in self.iter() {
+            // Safety: we've just checked that every byte fits
+            unsafe { out.push_unchecked(byte.to_ascii_lowercase()) };
+        }
+
+        Ok(())
+    }
+
+    /// Compares the contents of this [`PushArray`] with `other` in constant time.
+    ///
+    /// The time taken depends on the lengths being compared, but not on the
+    /// contents of either buffer, which makes this suitable for comparing MACs
+    /// or tokens. The regular `==` stops at the first mismatch instead.
+    ///
+    /// Note that this is best-effort: the comparison is written so that the
+    /// compiler can't see its result early, but no language guarantee exists.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut tag: PushArray<u8, 16> = PushArray::new();
+    /// tag.copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+    ///
+    /// assert!(tag.ct_eq(&[0xde, 0xad, 0xbe, 0xef]));
+    /// assert!(!tag.ct_eq(&[0xde, 0xad, 0xbe, 0xee]));
+    /// assert!(!tag.ct_eq(&[0xde, 0xad]));
+    /// ```
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let difference = self
+            .iter()
+            .zip(other)
+            .fold(0, |difference, (a, b)| difference | (a ^ b));
+
+        // Keep the compiler from short-circuiting the fold above
+        core::hint::black_box(difference) == 0
+    }
+
+    /// Push the given pieces to the back of this [`PushArray`], placing
+    /// `separator` between each of them.
+    ///
+    /// If the joined result does not fit, nothing is pushed and
+    /// [`Error::NotEnoughCapacity`] is returned.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut line: PushArray<u8, 16> = PushArray::new();
+    /// line.join(["21.5", "40", "1013"], b",").unwrap();
+    ///
+    /// assert_eq!(line.as_str(), Some("21.5,40,1013"));
+    ///
+    /// // Would overflow, so the buffer is left untouched
+    /// assert!(line.join(["a", "b", "c"], b", ").is_err());
+    /// assert_eq!(line.as_str(), Some("21.5,40,1013"));
+    /// ```
+    pub fn join<I, S>(&mut self, pieces: I, separator: &[u8]) -> Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<[u8]>,
+    {
+        let original_len = self.len();
+
+        let result = pieces.into_iter().enumerate().try_for_each(|(idx, piece)| {
+            if idx > 0 {
+                self.copy_from_slice(separator)?;
+            }
+            self.copy_from_slice(piece.as_ref())
+        });
+
+        if result.is_err() {
+            // Safety: bytes are trivially droppable, so rolling back is just
+            //         a matter of restoring the previous length
+            unsafe { self.set_len(original_len) };
+        }
+
+        result
+    }
+
+    /// Push the [`Display`](core::fmt::Display) representation of `value` to
+    /// the back of this [`PushArray`].
+    ///
+    /// If the text does not fit, nothing is pushed and
+    /// [`Error::NotEnoughCapacity`] is returned.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut line: PushArray<u8, 12> = PushArray::new();
+    /// line.push_str("temp=").unwrap();
+    /// line.push_display(&21.5).unwrap();
+    ///
+    /// assert_eq!(line.as_str(), Some("temp=21.5"));
+    ///
+    /// // Would overflow, so the buffer is left untouched
+    /// assert!(line.push_display(&"°C!!").is_err());
+    /// assert_eq!(line.as_str(), Some("temp=21.5"));
+    /// ```
+    pub fn push_display(&mut self, value: &impl core::fmt::Display) -> Result<()> {
+        use core::fmt::Write;
+
+        let original_len = self.len();
+
+        write!(self, "{value}").map_err(|_| {
+            // Safety: bytes are trivially droppable, so rolling back is just
+            //         a matter of restoring the previous length
+            unsafe { self.set_len(original_len) };
+            Error::NotEnoughCapacity
+        })
+    }
+
+    /// Push the shortest decimal representation of `value` that parses back
+    /// to the same `f32` to the back of this [`PushArray`].
+    ///
+    /// Values from `1e-5` up to `1e16` are written out in full, and integers
+    /// have no fractional part. Others are written with an exponent, such as
+    /// `1.5e-7` or `3e38`. Special values are written as `NaN`, `inf` and
+    /// `-inf`.
+    ///
+    /// The digits are computed with exact integer arithmetic, without going
+    /// through `core::fmt`, so the output is the same on every platform.
+    ///
+    /// If the text does not fit, nothing is pushed and
+    /// [`Error::NotEnoughCapacity`] is returned.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut reading: PushArray<u8, 16> = PushArray::new();
+    /// reading.push_f32(0.1 + 0.2).unwrap();
+    /// reading.push_str(" ").unwrap();
+    /// reading.push_f32(6.02e23).unwrap();
+    ///
+    /// assert_eq!(reading.as_str(), Some("0.3 6.02e23"));
+    /// ```
+    pub fn push_f32(&mut self, value: f32) -> Result<()> {
+        let mut buf = [0; ftoa::MAX_FLOAT_LEN];
+
+        self.copy_from_slice(ftoa::f32_shortest(value, &mut buf))
+    }
+
+    /// Push the shortest decimal representation of `value` that parses back
+    /// to the same `f64` to the back of this [`PushArray`].
+    ///
+    /// See [`push_f32`](Self::push_f32) for details on the format.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut reading: PushArray<u8, 32> = PushArray::new();
+    /// reading.push_f64(0.1 + 0.2).unwrap();
+    /// reading.push_str(" ").unwrap();
+    /// reading.push_f64(-1e-300).unwrap();
+    ///
+    /// assert_eq!(reading.as_str(), Some("0.30000000000000004 -1e-300"));
+    /// ```
+    pub fn push_f64(&mut self, value: f64) -> Result<()> {
+        let mut buf = [0; ftoa::MAX_FLOAT_LEN];
+
+        self.copy_from_slice(ftoa::f64_shortest(value, &mut buf))
+    }
+}
+
+macro_rules! impl_push_int {
+    ($($method:ident($ty:ty) => $routine:ident, $buf_len:ident, $what:literal;)*) => {
+        impl<const CAP: usize, L: LenType> PushArray<u8, CAP, L> {
+            $(
+                #[doc = concat!("Push ", $what, " of the given `", stringify!($ty), "` to the back of this [`PushArray`].")]
+                ///
+                /// This doesn't go through `core::fmt`, so it's considerably cheaper than
+                /// [`push_display`](Self::push_display).
+                ///
+                /// If the text does not fit, nothing is pushed and
+                /// [`Error::NotEnoughCapacity`] is returned.
+                pub fn $method(&mut self, value: $ty) -> Result<()> {
+                    let mut buf = [0; itoa::$buf_len];
+
+                    self.copy_from_slice(itoa::$routine(value, &mut buf))
+                }
+            )*
+        }
+    };
+}
+
+impl_push_int! {
+    push_u32_dec(u32) => u32_dec, MAX_DEC_LEN, "the decimal representation";
+    push_u64_dec(u64) => u64_dec, MAX_DEC_LEN, "the decimal representation";
+    push_i32_dec(i32) => i32_dec, MAX_DEC_LEN, "the decimal representation";
+    push_i64_dec(i64) => i64_dec, MAX_DEC_LEN, "the decimal representation";
+    push_u32_hex(u32) => u32_hex, MAX_HEX_LEN, "the lowercase hexadecimal representation, without a prefix,";
+    push_u64_hex(u64) => u64_hex, MAX_HEX_LEN, "the lowercase hexadecimal representation, without a prefix,";
 }
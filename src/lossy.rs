@@ -0,0 +1,111 @@
+use core::fmt::{self, Write};
+
+use crate::{LenType, PushArray};
+
+/// A `fmt::Write` adapter over a byte [`PushArray`] that truncates instead of
+/// failing once the array is full.
+///
+/// Output is cut at a char boundary, so the array stays valid UTF-8 if it was
+/// before. Everything written after the first truncation is discarded, so the
+/// array always holds a prefix of the output.
+///
+/// Created by [`PushArray::lossy_writer`] and
+/// [`PushString::lossy_writer`](crate::PushString::lossy_writer).
+///
+/// ```
+/// # use pushy::PushArray;
+/// use core::fmt::Write;
+///
+/// let mut line: PushArray<u8, 8> = PushArray::new();
+///
+/// let mut writer = line.lossy_writer();
+/// write!(writer, "temp={}", 21.5).unwrap();
+/// assert!(writer.is_truncated());
+///
+/// assert_eq!(line.as_str(), Some("temp=21."));
+/// ```
+pub struct LossyWriter<'a, const CAP: usize, L: LenType = usize> {
+    bytes: &'a mut PushArray<u8, CAP, L>,
+    truncated: bool,
+}
+
+impl<const CAP: usize, L: LenType> LossyWriter<'_, CAP, L> {
+    /// Returns true if some of the output was discarded for lack of room.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl<const CAP: usize, L: LenType> Write for LossyWriter<'_, CAP, L> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Ok(());
+        }
+
+        let room = CAP - self.bytes.len();
+        let mut end = s.len();
+        if end > room {
+            self.truncated = true;
+            end = room;
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+        }
+
+        // `end` is at most `room`, so this can't fail
+        let _ = self.bytes.copy_from_slice(&s.as_bytes()[..end]);
+
+        Ok(())
+    }
+}
+
+impl<const CAP: usize, L: LenType> PushArray<u8, CAP, L> {
+    /// Returns a `fmt::Write` adapter that truncates the output instead of
+    /// failing when this [`PushArray`] is full.
+    ///
+    /// See [`LossyWriter`] for details.
+    pub fn lossy_writer(&mut self) -> LossyWriter<'_, CAP, L> {
+        LossyWriter {
+            bytes: self,
+            truncated: false,
+        }
+    }
+
+    /// Formats `args` into this [`PushArray`], keeping as much of the output
+    /// as fits. Returns true if all of it did.
+    ///
+    /// Running out of capacity is not an error: the output is cut at a char
+    /// boundary, and nothing else is written. This never panics by itself,
+    /// which makes it usable from `#[panic_handler]`s and fault handlers, where
+    /// a panic would recurse.
+    ///
+    /// ```
+    /// # use pushy::PushArray;
+    /// let mut message: PushArray<u8, 16> = PushArray::new();
+    /// let (file, line) = ("src/motor.rs", 120);
+    ///
+    /// assert!(!message.format_lossy(format_args!("panicked at {file}:{line}")));
+    /// assert_eq!(message.as_str(), Some("panicked at src/"));
+    /// ```
+    ///
+    /// Within a panic handler:
+    ///
+    /// ```ignore
+    /// #[panic_handler]
+    /// fn panic(info: &core::panic::PanicInfo) -> ! {
+    ///     let mut message: PushArray<u8, 128> = PushArray::new();
+    ///     message.format_lossy(format_args!("{info}"));
+    ///     write_to_crash_log(&message);
+    ///
+    ///     loop {}
+    /// }
+    /// ```
+    pub fn format_lossy(&mut self, args: fmt::Arguments<'_>) -> bool {
+        let mut writer = self.lossy_writer();
+        // `LossyWriter` never fails, but a `Display` implementation might: the output
+        // is kept up to that point
+        let completed = writer.write_fmt(args).is_ok();
+
+        completed && !writer.truncated
+    }
+}
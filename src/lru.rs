@@ -0,0 +1,182 @@
+use core::borrow::Borrow;
+
+use crate::PushArray;
+
+/// A fixed-capacity least-recently-used cache.
+///
+/// Holds up to `CAP` entries. When inserting into a full cache, the least
+/// recently used entry is evicted to make room.
+///
+/// Lookups are linear in the amount of entries, which is fine for the small
+/// capacities this is meant for.
+///
+/// ```
+/// use pushy::LruCache;
+///
+/// let mut cache: LruCache<u32, &str, 2> = LruCache::new();
+/// cache.insert(1, "one");
+/// cache.insert(2, "two");
+///
+/// // Using 1 makes 2 the least recently used entry
+/// assert_eq!(cache.get(&1), Some(&"one"));
+///
+/// assert_eq!(cache.insert(3, "three"), Some((2, "two")));
+/// assert!(!cache.contains_key(&2));
+///
+/// let keys: Vec<_> = cache.iter().map(|(key, _)| *key).collect();
+/// assert_eq!(keys, [3, 1]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LruCache<K, V, const CAP: usize> {
+    /// Entries sorted from least to most recently used
+    entries: PushArray<(K, V), CAP>,
+}
+
+impl<K: Eq, V, const CAP: usize> LruCache<K, V, CAP> {
+    /// Creates an empty [`LruCache`].
+    pub const fn new() -> Self {
+        Self {
+            entries: PushArray::new(),
+        }
+    }
+
+    /// Returns the amount of entries in this cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if this cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns true if inserting a new key into this cache would evict an entry.
+    pub fn is_full(&self) -> bool {
+        self.entries.len() == CAP
+    }
+
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.entries.iter().position(|(k, _)| k.borrow() == key)
+    }
+
+    /// Moves the entry at the given index to the most recently used spot,
+    /// returning its new index.
+    fn touch(&mut self, index: usize) -> usize {
+        self.entries[index..].rotate_left(1);
+        self.entries.len() - 1
+    }
+
+    /// Returns the value of the given key, marking it as the most recently used.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        let index = self.touch(index);
+
+        Some(&self.entries[index].1)
+    }
+
+    /// Returns a mutable reference to the value of the given key, marking it as
+    /// the most recently used.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        let index = self.touch(index);
+
+        Some(&mut self.entries[index].1)
+    }
+
+    /// Returns the value of the given key without changing its recency.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).map(|index| &self.entries[index].1)
+    }
+
+    /// Returns true if this cache holds an entry with the given key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    /// Inserts an entry as the most recently used one.
+    ///
+    /// If the key was already present, its old entry is replaced and returned.
+    /// Otherwise, if the cache is full, the least recently used entry is evicted
+    /// and returned. If `CAP` is zero, the given entry is handed back.
+    pub fn insert(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(index) = self.position(&key) {
+            let index = self.touch(index);
+            return Some(core::mem::replace(&mut self.entries[index], (key, value)));
+        }
+
+        let evicted = if self.is_full() {
+            let Some(evicted) = self.pop_lru() else {
+                // Only happens if `CAP` is zero
+                return Some((key, value));
+            };
+            Some(evicted)
+        } else {
+            None
+        };
+
+        // Safety: there's room for the entry, since we either weren't full or
+        // just evicted an entry
+        unsafe { self.entries.push_unchecked((key, value)) };
+
+        evicted
+    }
+
+    /// Removes the entry with the given key, returning its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        self.touch(index);
+
+        self.entries.pop().map(|(_, value)| value)
+    }
+
+    /// Removes and returns the least recently used entry.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        if self.is_empty() {
+            return None;
+        }
+        self.touch(0);
+
+        self.entries.pop()
+    }
+
+    /// Returns an iterator over the entries of this cache, from the most to the
+    /// least recently used.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().rev().map(|(key, value)| (key, value))
+    }
+
+    /// Removes all entries from this cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<K: Eq, V, const CAP: usize> Default for LruCache<K, V, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
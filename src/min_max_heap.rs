@@ -0,0 +1,301 @@
+use crate::{PushArray, Result, TryFromIterator};
+
+/// A fixed-capacity double-ended priority queue.
+///
+/// Both the smallest and the greatest element can be looked at in constant
+/// time, and removed in logarithmic time. Pushing is logarithmic as well.
+///
+/// When full, [`push_evicting_min`](Self::push_evicting_min) and
+/// [`push_evicting_max`](Self::push_evicting_max) make room by evicting the
+/// worst element, which keeps the best `CAP` elements seen so far without
+/// sorting them on every insertion.
+///
+/// ```
+/// use pushy::MinMaxHeap;
+///
+/// // Keep the three best scores
+/// let mut best: MinMaxHeap<u32, 3> = MinMaxHeap::new();
+/// for score in [40, 95, 12, 77, 63, 88] {
+///     best.push_evicting_min(score);
+/// }
+///
+/// assert_eq!(best.peek_min(), Some(&77));
+/// assert_eq!(best.peek_max(), Some(&95));
+/// assert_eq!(best.into_sorted(), [77, 88, 95]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinMaxHeap<T, const CAP: usize> {
+    /// A min-max heap: elements on even levels (starting with the root) are
+    /// the smallest of their subtree, and elements on odd levels the greatest
+    items: PushArray<T, CAP>,
+}
+
+/// Returns true if `idx` is on an even level of the heap, where elements are
+/// the smallest of their subtree.
+fn is_min_level(idx: usize) -> bool {
+    (idx + 1).ilog2().is_multiple_of(2)
+}
+
+impl<T: Ord, const CAP: usize> MinMaxHeap<T, CAP> {
+    /// Creates an empty [`MinMaxHeap`].
+    pub const fn new() -> Self {
+        Self {
+            items: PushArray::new(),
+        }
+    }
+
+    /// Returns the amount of elements in this heap.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if this heap holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns true if this heap can't hold any more elements.
+    pub fn is_full(&self) -> bool {
+        self.items.len() == CAP
+    }
+
+    /// Returns the smallest element, or `None` if empty.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Returns the greatest element, or `None` if empty.
+    pub fn peek_max(&self) -> Option<&T> {
+        self.items.get(self.max_index()?)
+    }
+
+    /// Pushes an element into the heap.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity) if the
+    /// heap is full.
+    pub fn push(&mut self, value: T) -> Result<()> {
+        self.items.push_checked(value)?;
+        self.bubble_up(self.len() - 1);
+
+        Ok(())
+    }
+
+    /// Pushes an element into the heap, evicting the smallest element if the
+    /// heap is full, so that it keeps the greatest elements pushed into it.
+    ///
+    /// Returns the element that was left out: the evicted one, or `value`
+    /// itself if it's no greater than every element of the full heap.
+    pub fn push_evicting_min(&mut self, value: T) -> Option<T> {
+        if !self.is_full() {
+            // There's room, so this can't fail
+            let _ = self.push(value);
+            return None;
+        }
+
+        match self.items.first() {
+            Some(min) if value > *min => {
+                let evicted = core::mem::replace(&mut self.items.initialized_mut()[0], value);
+                self.trickle_down(0);
+
+                Some(evicted)
+            }
+            _ => Some(value),
+        }
+    }
+
+    /// Pushes an element into the heap, evicting the greatest element if the
+    /// heap is full, so that it keeps the smallest elements pushed into it.
+    ///
+    /// Returns the element that was left out: the evicted one, or `value`
+    /// itself if it's no smaller than every element of the full heap.
+    pub fn push_evicting_max(&mut self, value: T) -> Option<T> {
+        if !self.is_full() {
+            // There's room, so this can't fail
+            let _ = self.push(value);
+            return None;
+        }
+
+        match self.max_index() {
+            Some(idx) if value < self.items[idx] => {
+                let heap = self.items.initialized_mut();
+                let evicted = core::mem::replace(&mut heap[idx], value);
+                // The greatest element is a child of the root, unless it's the
+                // root itself, and its replacement may be the new smallest
+                if heap[idx] < heap[0] {
+                    heap.swap(idx, 0);
+                }
+                self.trickle_down(idx);
+
+                Some(evicted)
+            }
+            _ => Some(value),
+        }
+    }
+
+    /// Removes the smallest element and returns it, or `None` if empty.
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.remove_at(0)
+    }
+
+    /// Removes the greatest element and returns it, or `None` if empty.
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.remove_at(self.max_index()?)
+    }
+
+    /// Returns an iterator over the elements, in no particular order.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Returns the elements, sorted from smallest to greatest.
+    pub fn into_sorted(mut self) -> PushArray<T, CAP> {
+        let mut sorted = PushArray::new();
+        while let Some(min) = self.pop_min() {
+            // Both have the same capacity, so this can't fail
+            let _ = sorted.push_checked(min);
+        }
+
+        sorted
+    }
+
+    /// Removes all elements from this heap.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Returns the index of the greatest element: one of the children of the
+    /// root, or the root itself if it has none.
+    fn max_index(&self) -> Option<usize> {
+        match self.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ if self.items[2] > self.items[1] => Some(2),
+            _ => Some(1),
+        }
+    }
+
+    fn remove_at(&mut self, idx: usize) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+        self.items.initialized_mut().swap(idx, last);
+        let removed = self.items.pop();
+        if idx < last {
+            self.trickle_down(idx);
+        }
+
+        removed
+    }
+
+    /// Moves the element at `idx`, which was just pushed, up into place.
+    fn bubble_up(&mut self, idx: usize) {
+        if idx == 0 {
+            return;
+        }
+
+        let heap = self.items.initialized_mut();
+        let parent = (idx - 1) / 2;
+        let min_level = is_min_level(idx);
+
+        // An element on a min level that's greater than its parent belongs on
+        // the max levels, and the other way around
+        let out_of_place = if min_level {
+            heap[idx] > heap[parent]
+        } else {
+            heap[idx] < heap[parent]
+        };
+        if out_of_place {
+            heap.swap(idx, parent);
+            Self::bubble_up_levels(heap, parent, !min_level);
+        } else {
+            Self::bubble_up_levels(heap, idx, min_level);
+        }
+    }
+
+    /// Moves the element at `idx` up through the min levels if `min` is true,
+    /// or through the max levels otherwise.
+    fn bubble_up_levels(heap: &mut [T], mut idx: usize, min: bool) {
+        while idx >= 3 {
+            let grandparent = ((idx - 1) / 2 - 1) / 2;
+            let out_of_place = if min {
+                heap[idx] < heap[grandparent]
+            } else {
+                heap[idx] > heap[grandparent]
+            };
+            if !out_of_place {
+                break;
+            }
+
+            heap.swap(idx, grandparent);
+            idx = grandparent;
+        }
+    }
+
+    /// Moves the element at `idx` down into place, after it was replaced.
+    fn trickle_down(&mut self, mut idx: usize) {
+        let heap = self.items.initialized_mut();
+        let len = heap.len();
+        let min = is_min_level(idx);
+        // Whether `a` belongs closer to the root than `b` on this kind of level
+        let before = |a: &T, b: &T| if min { a < b } else { a > b };
+
+        loop {
+            // Find the best of the children and grandchildren
+            let first_child = 2 * idx + 1;
+            if first_child >= len {
+                break;
+            }
+            let descendants = [
+                first_child,
+                first_child + 1,
+                2 * first_child + 1,
+                2 * first_child + 2,
+                2 * first_child + 3,
+                2 * first_child + 4,
+            ];
+            let mut best = first_child;
+            for &candidate in &descendants[1..] {
+                if candidate < len && before(&heap[candidate], &heap[best]) {
+                    best = candidate;
+                }
+            }
+
+            if !before(&heap[best], &heap[idx]) {
+                break;
+            }
+            heap.swap(best, idx);
+
+            if best <= first_child + 1 {
+                // A child is on the other kind of level, and has no descendants
+                // on the same kind as `idx` that could be out of place
+                break;
+            }
+
+            // The element moved down two levels, and may now be on the wrong
+            // side of its new parent
+            let parent = (best - 1) / 2;
+            if before(&heap[parent], &heap[best]) {
+                heap.swap(best, parent);
+            }
+            idx = best;
+        }
+    }
+}
+
+impl<T: Ord, const CAP: usize> Default for MinMaxHeap<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Fails with [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity)
+/// if the iterator yields more than `CAP` elements.
+impl<T: Ord, const CAP: usize> TryFromIterator<T> for MinMaxHeap<T, CAP> {
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self> {
+        let mut heap = Self::new();
+        for value in iter {
+            heap.push(value)?;
+        }
+
+        Ok(heap)
+    }
+}
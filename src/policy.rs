@@ -0,0 +1,125 @@
+//! Policies deciding what happens when pushing into a full [`PushArray`].
+//!
+//! A policy is picked per push through [`PushArray::push_with`]:
+//!
+//! ```
+//! use pushy::{policy::OverwriteOldest, PushArray};
+//!
+//! let mut recent: PushArray<u32, 3> = PushArray::new();
+//! for reading in 1..=5 {
+//!     recent.push_with::<OverwriteOldest>(reading);
+//! }
+//!
+//! assert_eq!(recent.as_slice(), &[3, 4, 5]);
+//! ```
+
+use crate::{LenType, PushArray, Result};
+
+/// Decides what happens when pushing an element into a full [`PushArray`].
+pub trait OverflowPolicy<T> {
+    /// The result of pushing with this policy.
+    type Output;
+
+    /// Pushes `value` into `arr`, applying this policy if `arr` is full.
+    fn push<const CAP: usize, L: LenType>(arr: &mut PushArray<T, CAP, L>, value: T)
+        -> Self::Output;
+}
+
+/// Panics on overflow, like [`PushArray::push`].
+///
+/// Not available with the `no-panic-api` feature.
+#[cfg(not(feature = "no-panic-api"))]
+#[derive(Debug, Clone, Copy)]
+pub struct Panic;
+
+#[cfg(not(feature = "no-panic-api"))]
+impl<T> OverflowPolicy<T> for Panic {
+    type Output = ();
+
+    fn push<const CAP: usize, L: LenType>(arr: &mut PushArray<T, CAP, L>, value: T) {
+        arr.push(value)
+    }
+}
+
+/// Returns [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity) on overflow, like [`PushArray::push_checked`].
+#[derive(Debug, Clone, Copy)]
+pub struct Reject;
+
+impl<T> OverflowPolicy<T> for Reject {
+    type Output = Result<()>;
+
+    fn push<const CAP: usize, L: LenType>(arr: &mut PushArray<T, CAP, L>, value: T) -> Result<()> {
+        arr.push_checked(value)
+    }
+}
+
+/// Silently drops the new element on overflow, keeping the array as is.
+///
+/// Returns whether the element was pushed.
+#[derive(Debug, Clone, Copy)]
+pub struct Saturate;
+
+impl<T> OverflowPolicy<T> for Saturate {
+    type Output = bool;
+
+    fn push<const CAP: usize, L: LenType>(arr: &mut PushArray<T, CAP, L>, value: T) -> bool {
+        arr.push_checked(value).is_ok()
+    }
+}
+
+/// Removes the oldest (first) element on overflow to make room for the new one.
+///
+/// Returns the element that was evicted, if any.
+#[derive(Debug, Clone, Copy)]
+pub struct OverwriteOldest;
+
+impl<T> OverflowPolicy<T> for OverwriteOldest {
+    type Output = Option<T>;
+
+    fn push<const CAP: usize, L: LenType>(arr: &mut PushArray<T, CAP, L>, value: T) -> Option<T> {
+        if !arr.is_fully_initialized() {
+            // Safety: there's room for at least one more element
+            unsafe { arr.push_unchecked(value) };
+            return None;
+        }
+
+        if CAP == 0 {
+            // There's nothing to evict, so the new element is the one that goes
+            return Some(value);
+        }
+
+        // Safety: the array is full, so its first element is initialized.
+        //         It's moved out and the remaining elements are shifted down
+        //         by one, which leaves a free slot at the end for `value`.
+        unsafe {
+            let ptr = arr.as_mut_ptr();
+            let oldest = ptr.read();
+            core::ptr::copy(ptr.add(1), ptr, CAP - 1);
+            ptr.add(CAP - 1).write(value);
+
+            Some(oldest)
+        }
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Push an element to the back of this [`PushArray`], letting the
+    /// [`OverflowPolicy`] `P` decide what happens if the array is full.
+    ///
+    /// See the [`policy`](crate::policy) module for the available policies.
+    ///
+    /// ```
+    /// use pushy::{policy::Saturate, PushArray};
+    ///
+    /// let mut arr: PushArray<u8, 2> = PushArray::new();
+    ///
+    /// assert!(arr.push_with::<Saturate>(1));
+    /// assert!(arr.push_with::<Saturate>(2));
+    /// assert!(!arr.push_with::<Saturate>(3));
+    ///
+    /// assert_eq!(arr.as_slice(), &[1, 2]);
+    /// ```
+    pub fn push_with<P: OverflowPolicy<T>>(&mut self, value: T) -> P::Output {
+        P::push(self, value)
+    }
+}
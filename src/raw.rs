@@ -0,0 +1,178 @@
+//! The parts of [`PushArray`](crate::PushArray) that don't depend on `CAP`.
+//!
+//! Every distinct `CAP` gets its own copy of every method, so the code behind
+//! the bulk operations lives here instead, on a `(*mut T, len, capacity)` core
+//! that's generic over the element and length types only. The methods of
+//! `PushArray` then only forward their buffer and length, and stay small enough
+//! to be inlined.
+//!
+//! The loops are kept out of line, so that the copies `CAP` would otherwise
+//! make don't come back through inlining.
+
+use core::mem::MaybeUninit;
+
+use crate::{not_enough_capacity, LenType, Result, SetLenOnDrop};
+
+/// Fails if `additional` more elements don't fit in a buffer of `capacity`
+/// elements, `len` of which are in use.
+pub(crate) fn check_capacity(capacity: usize, len: usize, additional: usize) -> Result<()> {
+    if additional > capacity - len {
+        Err(not_enough_capacity())
+    } else {
+        Ok(())
+    }
+}
+
+/// Copies `slice` right after the first `len` elements of `buf`, returning the
+/// new length. If `slice` does not fit, nothing is copied.
+pub(crate) fn extend_from_slice<T: Copy>(
+    buf: &mut [MaybeUninit<T>],
+    len: usize,
+    slice: &[T],
+) -> Result<usize> {
+    check_capacity(buf.len(), len, slice.len())?;
+
+    // Safety: we've just checked that there is enough storage to hold the new
+    //         elements, which are trivially copiable since they implement Copy
+    unsafe {
+        core::ptr::copy_nonoverlapping(
+            slice.as_ptr(),
+            buf.as_mut_ptr().add(len).cast::<T>(),
+            slice.len(),
+        )
+    };
+
+    Ok(len + slice.len())
+}
+
+/// Drops the elements of `buf` in `start..end`.
+///
+/// # Safety
+///
+/// The elements in `start..end` must be initialized, and must no longer be
+/// considered initialized by the caller.
+pub(crate) unsafe fn drop_range<T>(buf: &mut [MaybeUninit<T>], start: usize, end: usize) {
+    let range: *mut [T] = &mut buf[start..end] as *mut [MaybeUninit<T>] as *mut [T];
+
+    // Safety: guaranteed by the caller
+    unsafe { core::ptr::drop_in_place(range) };
+}
+
+/// Writes the items of `iter` after the first `len` elements of `ptr`, until
+/// there's no room left for them or the iterator runs out. Returns how many
+/// items were written.
+///
+/// If the iterator panics, the items written so far are kept.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of `capacity` elements, the first `len` of
+/// which are initialized.
+#[inline(never)]
+pub(crate) unsafe fn push_iter<T, L: LenType>(
+    ptr: *mut T,
+    len: &mut L,
+    capacity: usize,
+    iter: impl Iterator<Item = T>,
+) -> usize {
+    let mut len = SetLenOnDrop::new(len);
+    let original_len = len.current_len();
+
+    for item in iter.take(capacity - original_len) {
+        // Safety: `take` stops before the remaining capacity runs out
+        unsafe { ptr.add(len.current_len()).write(item) };
+        len.increment_len(1);
+    }
+
+    len.current_len() - original_len
+}
+
+/// Writes `n` clones of `value` after the first `len` elements of `ptr`, or
+/// nothing if they don't fit.
+///
+/// If cloning panics, the clones written so far are kept.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of `capacity` elements, the first `len` of
+/// which are initialized.
+#[inline(never)]
+pub(crate) unsafe fn push_repeat<T: Clone, L: LenType>(
+    ptr: *mut T,
+    len: &mut L,
+    capacity: usize,
+    value: T,
+    n: usize,
+) -> Result<()> {
+    let mut len = SetLenOnDrop::new(len);
+    check_capacity(capacity, len.current_len(), n)?;
+
+    if n == 0 {
+        return Ok(());
+    }
+
+    // Safety: we've just checked that there's room for `n` elements
+    unsafe {
+        for _ in 1..n {
+            ptr.add(len.current_len()).write(value.clone());
+            len.increment_len(1);
+        }
+        // Move the original value in last, saving one clone
+        ptr.add(len.current_len()).write(value);
+        len.increment_len(1);
+    }
+
+    Ok(())
+}
+
+/// Moves the elements of a buffer out one at a time, and drops those that
+/// weren't moved out when dropped itself.
+///
+/// This is what makes loops that move elements out of a buffer while calling
+/// into user code panic-safe: if that code panics, the elements left behind
+/// are dropped during unwinding instead of leaked.
+pub(crate) struct MoveOut<'a, T> {
+    buf: &'a mut [MaybeUninit<T>],
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> MoveOut<'a, T> {
+    /// # Safety
+    ///
+    /// The elements in `start..end` of `buf` must be initialized, and must no
+    /// longer be considered initialized by the caller.
+    pub(crate) unsafe fn new(buf: &'a mut [MaybeUninit<T>], start: usize, end: usize) -> Self {
+        Self { buf, start, end }
+    }
+
+    /// Returns a reference to the element that would be moved out next.
+    pub(crate) fn peek(&self) -> Option<&T> {
+        // Safety: the elements in `start..end` are initialized
+        (self.start < self.end).then(|| unsafe { self.buf[self.start].assume_init_ref() })
+    }
+}
+
+impl<T> Iterator for MoveOut<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        // Safety: the element at `start` is initialized, and is no longer
+        //         considered so once `start` moves past it
+        let elem = unsafe { self.buf[self.start].assume_init_read() };
+        self.start += 1;
+
+        Some(elem)
+    }
+}
+
+impl<T> Drop for MoveOut<'_, T> {
+    fn drop(&mut self) {
+        // Safety: the elements in `start..end` are initialized and owned by us
+        unsafe { drop_range(self.buf, self.start, self.end) };
+    }
+}
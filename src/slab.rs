@@ -0,0 +1,151 @@
+use crate::{PushArray, Result};
+
+#[derive(Debug, Clone)]
+enum Entry<T> {
+    Occupied(T),
+    /// A free slot, holding the key of the next free slot
+    Vacant(usize),
+}
+
+/// A fixed-capacity slab: stores values in slots and hands out keys to them.
+///
+/// Keys stay valid until their value is removed, no matter what happens to
+/// other values. The slots of removed values are reused by later insertions.
+///
+/// ```
+/// use pushy::PushSlab;
+///
+/// let mut requests: PushSlab<&str, 4> = PushSlab::new();
+///
+/// let a = requests.insert("GET /").unwrap();
+/// let b = requests.insert("GET /favicon.ico").unwrap();
+///
+/// assert_eq!(requests.remove(a), Some("GET /"));
+/// // Removing `a` doesn't affect `b`
+/// assert_eq!(requests.get(b), Some(&"GET /favicon.ico"));
+///
+/// // The slot of `a` gets reused
+/// let c = requests.insert("POST /login").unwrap();
+/// assert_eq!(c, a);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PushSlab<T, const CAP: usize> {
+    entries: PushArray<Entry<T>, CAP>,
+    /// Key of the first free slot. Equal to `entries.len()` when there are no
+    /// free slots before the end.
+    next_free: usize,
+    len: usize,
+}
+
+impl<T, const CAP: usize> PushSlab<T, CAP> {
+    /// Creates an empty [`PushSlab`].
+    pub const fn new() -> Self {
+        Self {
+            entries: PushArray::new(),
+            next_free: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the amount of values stored in this [`PushSlab`].
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if this [`PushSlab`] holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if this [`PushSlab`] can't hold any more values.
+    pub fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    /// Stores a value in this [`PushSlab`], returning its key.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`](crate::Error::NotEnoughCapacity) if all slots are taken.
+    pub fn insert(&mut self, value: T) -> Result<usize> {
+        let key = self.next_free;
+
+        match self.entries.get_mut(key) {
+            Some(entry) => match *entry {
+                Entry::Vacant(next_free) => {
+                    *entry = Entry::Occupied(value);
+                    self.next_free = next_free;
+                }
+                Entry::Occupied(_) => unreachable!("the free list points to an occupied slot"),
+            },
+            None => {
+                self.entries.push_checked(Entry::Occupied(value))?;
+                self.next_free = self.entries.len();
+            }
+        }
+
+        self.len += 1;
+
+        Ok(key)
+    }
+
+    /// Returns a reference to the value with the given key.
+    pub fn get(&self, key: usize) -> Option<&T> {
+        match self.entries.get(key)? {
+            Entry::Occupied(value) => Some(value),
+            Entry::Vacant(_) => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value with the given key.
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        match self.entries.get_mut(key)? {
+            Entry::Occupied(value) => Some(value),
+            Entry::Vacant(_) => None,
+        }
+    }
+
+    /// Returns true if a value with the given key is stored.
+    pub fn contains(&self, key: usize) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes the value with the given key, freeing its slot.
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let entry = self.entries.get_mut(key)?;
+        if let Entry::Vacant(_) = entry {
+            return None;
+        }
+
+        let value = match core::mem::replace(entry, Entry::Vacant(self.next_free)) {
+            Entry::Occupied(value) => value,
+            Entry::Vacant(_) => unreachable!(),
+        };
+        self.next_free = key;
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    /// Removes all values from this [`PushSlab`].
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.next_free = 0;
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the keys and values of this [`PushSlab`], in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(key, entry)| match entry {
+                Entry::Occupied(value) => Some((key, value)),
+                Entry::Vacant(_) => None,
+            })
+    }
+}
+
+impl<T, const CAP: usize> Default for PushSlab<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
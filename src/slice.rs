@@ -0,0 +1,192 @@
+use core::{
+    fmt::{self, Debug},
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(not(feature = "no-panic-api"))]
+use crate::capacity_overflow;
+use crate::{raw, Result};
+
+/// A [`PushArray`](crate::PushArray)-like buffer over storage provided by the
+/// caller.
+///
+/// The capacity is the length of the storage, so it's decided at runtime
+/// rather than by a const generic. This allows writing functions once for
+/// buffers of any capacity, such as chunks of a larger arena.
+///
+/// The initialized elements are dropped along with the [`PushSlice`], and the
+/// storage is left uninitialized.
+///
+/// ```
+/// use core::mem::MaybeUninit;
+/// use pushy::PushSlice;
+///
+/// fn greet(out: &mut PushSlice<'_, u8>) -> pushy::Result<()> {
+///     out.copy_from_slice(b"hello")
+/// }
+///
+/// let mut storage = [MaybeUninit::uninit(); 16];
+/// let mut out = PushSlice::new(&mut storage);
+///
+/// greet(&mut out).unwrap();
+/// assert_eq!(out.as_slice(), b"hello");
+/// assert_eq!(out.capacity(), 16);
+/// ```
+pub struct PushSlice<'a, T> {
+    buf: &'a mut [MaybeUninit<T>],
+    len: usize,
+}
+
+impl<'a, T> PushSlice<'a, T> {
+    /// Creates an empty [`PushSlice`] over `storage`.
+    ///
+    /// Whatever `storage` held is ignored, and will be overwritten.
+    pub const fn new(storage: &'a mut [MaybeUninit<T>]) -> Self {
+        Self {
+            buf: storage,
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum amount of elements this [`PushSlice`] can hold.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the amount of initialized elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no initialized elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if this [`PushSlice`] can't hold any more elements.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Returns an error if there's no room for `additional` more elements.
+    pub fn ensure_capacity_for(&self, additional: usize) -> Result<()> {
+        raw::check_capacity(self.capacity(), self.len, additional)
+    }
+
+    /// Pushes an element to the back, failing if there's no room for it.
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use pushy::PushSlice;
+    ///
+    /// let mut storage = [const { MaybeUninit::uninit() }; 1];
+    /// let mut names = PushSlice::new(&mut storage);
+    ///
+    /// assert!(names.push_checked("a").is_ok());
+    /// assert!(names.push_checked("b").is_err());
+    /// ```
+    pub fn push_checked(&mut self, value: T) -> Result<()> {
+        self.ensure_capacity_for(1)?;
+
+        self.buf[self.len].write(value);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Pushes an element to the back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's no room for the element.
+    ///
+    /// Not available with the `no-panic-api` feature.
+    #[cfg(not(feature = "no-panic-api"))]
+    pub fn push(&mut self, value: T) {
+        if self.is_full() {
+            capacity_overflow();
+        }
+
+        self.buf[self.len].write(value);
+        self.len += 1;
+    }
+
+    /// Removes the last element and returns it, or `None` if empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let new_len = self.len.checked_sub(1)?;
+        self.len = new_len;
+
+        // Safety: the last element was initialized, and we've given up ownership
+        //         of it by shrinking our length
+        Some(unsafe { self.buf[new_len].assume_init_read() })
+    }
+
+    /// Drops every initialized element.
+    pub fn clear(&mut self) {
+        let len = self.len;
+        self.len = 0;
+
+        // Safety: the elements are no longer considered initialized once the
+        //         length is reset, so they are dropped exactly once
+        unsafe { raw::drop_range(self.buf, 0, len) };
+    }
+
+    /// Returns the initialized elements.
+    pub fn as_slice(&self) -> &[T] {
+        // Safety: the first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the initialized elements, mutably.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: the first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast(), self.len) }
+    }
+}
+
+impl<T: Copy> PushSlice<'_, T> {
+    /// Copies all elements of `slice` to the back, or none of them if there's
+    /// not enough room.
+    pub fn copy_from_slice(&mut self, slice: &[T]) -> Result<()> {
+        self.len = raw::extend_from_slice(self.buf, self.len, slice)?;
+
+        Ok(())
+    }
+}
+
+impl<T> Deref for PushSlice<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> DerefMut for PushSlice<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+/// Formats like [`PushArray`](crate::PushArray): as a slice, or with the
+/// length and capacity under `{:#?}`.
+impl<T: Debug> Debug for PushSlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("PushSlice")
+                .field("len", &self.len())
+                .field("capacity", &self.capacity())
+                .field("initialized", &self.as_slice())
+                .finish()
+        } else {
+            self.as_slice().fmt(f)
+        }
+    }
+}
+
+impl<T> Drop for PushSlice<'_, T> {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
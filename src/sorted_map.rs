@@ -0,0 +1,440 @@
+//! A map that keeps its entries sorted by key, and its entry API.
+
+use core::{
+    borrow::Borrow,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{Error, PushArray, Result, TryFromIterator};
+
+/// A fixed-capacity map that keeps its entries sorted by key.
+///
+/// Lookups use binary search and are logarithmic in the amount of entries.
+/// Insertions and removals shift the entries after the affected one, so they're
+/// linear.
+///
+/// ```
+/// use pushy::SortedMap;
+///
+/// let mut ports: SortedMap<&str, u16, 4> = SortedMap::new();
+/// ports.insert("https", 443).unwrap();
+/// ports.insert("ssh", 22).unwrap();
+/// ports.insert("http", 80).unwrap();
+///
+/// assert_eq!(ports.get("ssh"), Some(&22));
+/// assert_eq!(ports.insert("ssh", 2222).unwrap(), Some(22));
+///
+/// let keys: Vec<_> = ports.keys().copied().collect();
+/// assert_eq!(keys, ["http", "https", "ssh"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SortedMap<K, V, const CAP: usize> {
+    /// Entries sorted by key, without duplicate keys
+    entries: PushArray<(K, V), CAP>,
+}
+
+impl<K: Ord, V, const CAP: usize> SortedMap<K, V, CAP> {
+    /// Creates an empty [`SortedMap`].
+    pub const fn new() -> Self {
+        Self {
+            entries: PushArray::new(),
+        }
+    }
+
+    /// Returns the amount of entries in this map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if this map holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns true if this map can't hold any more entries.
+    pub fn is_full(&self) -> bool {
+        self.entries.len() == CAP
+    }
+
+    /// Returns the index of the entry with the given key, or the index where it
+    /// would be inserted.
+    fn search<Q>(&self, key: &Q) -> core::result::Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.entries.binary_search_by(|(k, _)| k.borrow().cmp(key))
+    }
+
+    fn ensure_room(&self) -> Result<()> {
+        match self.is_full() {
+            true => Err(Error::NotEnoughCapacity),
+            false => Ok(()),
+        }
+    }
+
+    /// Inserts an entry at the given index, which must keep the keys sorted.
+    /// The map must not be full.
+    fn insert_at(&mut self, index: usize, key: K, value: V) {
+        debug_assert!(!self.is_full());
+        // Safety: callers check that there's room for one more entry
+        unsafe { self.entries.push_unchecked((key, value)) };
+        self.entries[index..].rotate_right(1);
+    }
+
+    fn remove_at(&mut self, index: usize) -> Option<(K, V)> {
+        self.entries.get(index)?;
+        self.entries[index..].rotate_left(1);
+
+        self.entries.pop()
+    }
+
+    /// Returns a reference to the value of the given key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+
+        Some(&self.entries[index].1)
+    }
+
+    /// Returns a mutable reference to the value of the given key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+
+        Some(&mut self.entries[index].1)
+    }
+
+    /// Returns true if this map holds an entry with the given key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(key).is_ok()
+    }
+
+    /// Inserts an entry into this map.
+    ///
+    /// If the key was already present, its value is replaced and the old value
+    /// is returned.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`] if the key is new and the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>> {
+        match self.search(&key) {
+            Ok(index) => Ok(Some(core::mem::replace(&mut self.entries[index].1, value))),
+            Err(index) => {
+                self.ensure_room()?;
+                self.insert_at(index, key, value);
+
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns the entry of the given key, for in-place manipulation with a
+    /// single lookup.
+    ///
+    /// ```
+    /// use pushy::SortedMap;
+    ///
+    /// let mut counts: SortedMap<char, u32, 8> = SortedMap::new();
+    /// for ch in "hello".chars() {
+    ///     *counts.entry(ch).or_insert(0).unwrap() += 1;
+    /// }
+    ///
+    /// assert_eq!(counts.get(&'l'), Some(&2));
+    /// assert_eq!(counts.get(&'o'), Some(&1));
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, CAP> {
+        match self.search(&key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            Err(index) => Entry::Vacant(VacantEntry {
+                map: self,
+                key,
+                index,
+            }),
+        }
+    }
+
+    /// Removes the entry with the given key, returning its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+
+        self.remove_at(index).map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the entries of this map, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Returns an iterator over the entries of this map, sorted by key, with
+    /// mutable references to the values.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.entries.iter_mut().map(|(key, value)| (&*key, value))
+    }
+
+    /// Returns an iterator over the keys of this map, in order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values of this map, sorted by their keys.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the entries whose keys are within `range`,
+    /// sorted by key.
+    ///
+    /// Unlike `BTreeMap::range`, an inverted range doesn't panic, and yields
+    /// nothing instead.
+    ///
+    /// ```
+    /// use pushy::SortedMap;
+    ///
+    /// let mut timers: SortedMap<u32, &str, 8> = SortedMap::new();
+    /// timers.insert(100, "blink").unwrap();
+    /// timers.insert(250, "poll").unwrap();
+    /// timers.insert(900, "report").unwrap();
+    ///
+    /// // Everything due by t = 300
+    /// let due: Vec<_> = timers.range(..=300).map(|(_, name)| *name).collect();
+    /// assert_eq!(due, ["blink", "poll"]);
+    ///
+    /// assert_eq!(timers.range(101..900).count(), 1);
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> impl DoubleEndedIterator<Item = (&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(key) => self.search(key).unwrap_or_else(|index| index),
+            Bound::Excluded(key) => self
+                .search(key)
+                .map_or_else(|index| index, |index| index + 1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(key) => self
+                .search(key)
+                .map_or_else(|index| index, |index| index + 1),
+            Bound::Excluded(key) => self.search(key).unwrap_or_else(|index| index),
+            Bound::Unbounded => self.len(),
+        };
+
+        self.entries[start..end.max(start)]
+            .iter()
+            .map(|(key, value)| (key, value))
+    }
+
+    /// Returns the entry with the smallest key.
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.first().map(|(key, value)| (key, value))
+    }
+
+    /// Returns the entry with the largest key.
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.last().map(|(key, value)| (key, value))
+    }
+
+    /// Removes the entry with the smallest key and returns it.
+    ///
+    /// ```
+    /// use pushy::SortedMap;
+    ///
+    /// let mut deadlines: SortedMap<u32, &str, 4> = SortedMap::new();
+    /// deadlines.insert(30, "b").unwrap();
+    /// deadlines.insert(10, "a").unwrap();
+    ///
+    /// assert_eq!(deadlines.pop_first(), Some((10, "a")));
+    /// assert_eq!(deadlines.first_key_value(), Some((&30, &"b")));
+    /// ```
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        self.remove_at(0)
+    }
+
+    /// Removes the entry with the largest key and returns it.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.entries.pop()
+    }
+
+    /// Removes all entries from this map.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<K: Ord, V, const CAP: usize> Default for SortedMap<K, V, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Inserts key-value pairs into a new [`SortedMap`], as with
+/// [`insert`](SortedMap::insert): later values replace earlier ones for the
+/// same key. Fails if there are more than `CAP` distinct keys.
+impl<K: Ord, V, const CAP: usize> TryFromIterator<(K, V)> for SortedMap<K, V, CAP> {
+    fn try_from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self> {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            map.insert(key, value)?;
+        }
+
+        Ok(map)
+    }
+}
+
+/// An entry of a [`SortedMap`], which may or may not hold a value.
+///
+/// Created by [`SortedMap::entry`].
+pub enum Entry<'a, K, V, const CAP: usize> {
+    /// The map holds a value for the key.
+    Occupied(OccupiedEntry<'a, K, V, CAP>),
+    /// The map doesn't hold a value for the key.
+    Vacant(VacantEntry<'a, K, V, CAP>),
+}
+
+impl<'a, K: Ord, V, const CAP: usize> Entry<'a, K, V, CAP> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Returns the value of this entry, inserting `default` if it's vacant.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`] if the entry is vacant and the map
+    /// is full.
+    pub fn or_insert(self, default: V) -> Result<&'a mut V> {
+        self.or_insert_with(|| default)
+    }
+
+    /// Returns the value of this entry, inserting the result of `default` if
+    /// it's vacant.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`] if the entry is vacant and the map
+    /// is full, in which case `default` isn't called.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> Result<&'a mut V> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                entry.map.ensure_room()?;
+                entry.insert(default())
+            }
+        }
+    }
+
+    /// Returns the value of this entry, inserting `V::default()` if it's vacant.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`] if the entry is vacant and the map
+    /// is full.
+    pub fn or_default(self) -> Result<&'a mut V>
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Calls `f` with the value of this entry if it's occupied.
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+/// An entry of a [`SortedMap`] that holds a value.
+pub struct OccupiedEntry<'a, K, V, const CAP: usize> {
+    map: &'a mut SortedMap<K, V, CAP>,
+    index: usize,
+}
+
+impl<'a, K: Ord, V, const CAP: usize> OccupiedEntry<'a, K, V, CAP> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        &self.map.entries[self.index].0
+    }
+
+    /// Returns the value of this entry.
+    pub fn get(&self) -> &V {
+        &self.map.entries[self.index].1
+    }
+
+    /// Returns the value of this entry, mutably.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.entries[self.index].1
+    }
+
+    /// Turns this entry into a mutable reference to its value, with the
+    /// lifetime of the map.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.entries[self.index].1
+    }
+
+    /// Replaces the value of this entry, returning the old one.
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes this entry from the map, returning its key and value.
+    pub fn remove_entry(self) -> (K, V) {
+        match self.map.remove_at(self.index) {
+            Some(entry) => entry,
+            // The index comes from a successful search, so it's in-bounds
+            None => unreachable!(),
+        }
+    }
+
+    /// Removes this entry from the map, returning its value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+/// An entry of a [`SortedMap`] that doesn't hold a value.
+pub struct VacantEntry<'a, K, V, const CAP: usize> {
+    map: &'a mut SortedMap<K, V, CAP>,
+    key: K,
+    /// Where the key would be inserted
+    index: usize,
+}
+
+impl<'a, K: Ord, V, const CAP: usize> VacantEntry<'a, K, V, CAP> {
+    /// Returns the key of this entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes the key of this entry back.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts a value into this entry, returning a reference to it.
+    ///
+    /// Returns [`Error::NotEnoughCapacity`] if the map is full.
+    pub fn insert(self, value: V) -> Result<&'a mut V> {
+        self.map.ensure_room()?;
+        self.map.insert_at(self.index, self.key, value);
+
+        Ok(&mut self.map.entries[self.index].1)
+    }
+}
@@ -0,0 +1,629 @@
+use core::{
+    fmt,
+    iter::FusedIterator,
+    ops::{Bound, Deref, DerefMut, RangeBounds},
+    str::{Chars, FromStr},
+};
+
+#[cfg(not(feature = "no-panic-api"))]
+use crate::capacity_overflow;
+use crate::{Error, LossyWriter, PushArray, Result, TryFromIterator};
+
+/// A fixed-capacity, stack-allocated string.
+///
+/// Holds up to `CAP` bytes of UTF-8 text. Unlike a `PushArray<u8, CAP>`, the
+/// contents are always valid UTF-8, so they can be accessed as a `&str` directly.
+///
+/// ```
+/// use pushy::PushString;
+///
+/// let mut greeting: PushString<16> = "Hello".try_into().unwrap();
+/// greeting.push_str(", world").unwrap();
+/// greeting.push_char('!').unwrap();
+///
+/// assert_eq!(&*greeting, "Hello, world!");
+/// assert!(greeting.push_str(" Goodbye").is_err());
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PushString<const CAP: usize> {
+    bytes: PushArray<u8, CAP>,
+}
+
+impl<const CAP: usize> PushString<CAP> {
+    /// Creates an empty [`PushString`].
+    pub const fn new() -> Self {
+        Self {
+            bytes: PushArray::new(),
+        }
+    }
+
+    /// Returns the length of this string, in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns true if this string has no contents.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the contents of this string.
+    pub fn as_str(&self) -> &str {
+        // Safety: `bytes` only ever receives valid UTF-8, and is only ever
+        //         truncated at char boundaries
+        unsafe { core::str::from_utf8_unchecked(&self.bytes) }
+    }
+
+    /// Returns the contents of this string, mutably.
+    pub fn as_mut_str(&mut self) -> &mut str {
+        // Safety: see `as_str`
+        unsafe { core::str::from_utf8_unchecked_mut(&mut self.bytes) }
+    }
+
+    /// Returns the contents of this string as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Push a string slice to the end of this string.
+    ///
+    /// If it does not fit, nothing is pushed and [`Error::NotEnoughCapacity`]
+    /// is returned.
+    pub fn push_str(&mut self, value: &str) -> Result<()> {
+        self.bytes.push_str(value)
+    }
+
+    /// Returns a `fmt::Write` adapter that truncates the output at a char
+    /// boundary instead of failing when this string is full.
+    ///
+    /// See [`LossyWriter`] for details.
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    /// use pushy::PushString;
+    ///
+    /// let mut status: PushString<10> = PushString::new();
+    /// write!(status.lossy_writer(), "{}% done", 100).unwrap();
+    ///
+    /// assert_eq!(&*status, "100% done");
+    /// ```
+    pub fn lossy_writer(&mut self) -> LossyWriter<'_, CAP> {
+        // `LossyWriter` only ever writes whole chars, so `bytes` stays valid UTF-8
+        self.bytes.lossy_writer()
+    }
+
+    /// Push a char to the end of this string.
+    ///
+    /// If it does not fit, nothing is pushed and [`Error::NotEnoughCapacity`]
+    /// is returned.
+    pub fn push_char(&mut self, ch: char) -> Result<()> {
+        self.push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Collects chars into a new [`PushString`].
+    ///
+    /// Fails with [`Error::NotEnoughCapacity`] if they take more than `CAP`
+    /// bytes.
+    ///
+    /// ```
+    /// use pushy::PushString;
+    ///
+    /// let ascii = PushString::<8>::try_from_chars("añb".chars().filter(char::is_ascii));
+    /// assert_eq!(ascii.unwrap().as_str(), "ab");
+    ///
+    /// assert!(PushString::<2>::try_from_chars("abc".chars()).is_err());
+    /// ```
+    pub fn try_from_chars<I: IntoIterator<Item = char>>(chars: I) -> Result<Self> {
+        let mut string = Self::new();
+        for ch in chars {
+            string.push_char(ch)?;
+        }
+
+        Ok(string)
+    }
+
+    /// Concatenates string slices into a new [`PushString`].
+    ///
+    /// Fails with [`Error::NotEnoughCapacity`] if they take more than `CAP`
+    /// bytes.
+    pub fn try_from_strs<'a, I: IntoIterator<Item = &'a str>>(pieces: I) -> Result<Self> {
+        let mut string = Self::new();
+        for piece in pieces {
+            string.push_str(piece)?;
+        }
+
+        Ok(string)
+    }
+
+    /// Appends a copy of this string to `out`, with ASCII letters converted
+    /// to uppercase.
+    ///
+    /// If it does not fit, nothing is appended and
+    /// [`Error::NotEnoughCapacity`] is returned. For in-place conversion, see
+    /// [`make_ascii_uppercase`](str::make_ascii_uppercase).
+    ///
+    /// ```
+    /// use pushy::PushString;
+    ///
+    /// let header: PushString<16> = "Content-Length".parse().unwrap();
+    /// let mut canonical: PushString<16> = PushString::new();
+    /// header.to_ascii_lowercase_into(&mut canonical).unwrap();
+    ///
+    /// assert_eq!(&*canonical, "content-length");
+    /// ```
+    pub fn to_ascii_uppercase_into<const N: usize>(&self, out: &mut PushString<N>) -> Result<()> {
+        // Changing the case of ASCII letters keeps the bytes valid UTF-8
+        self.bytes.to_ascii_uppercase_into(&mut out.bytes)
+    }
+
+    /// Appends a copy of this string to `out`, with ASCII letters converted
+    /// to lowercase.
+    ///
+    /// If it does not fit, nothing is appended and
+    /// [`Error::NotEnoughCapacity`] is returned. For in-place conversion, see
+    /// [`make_ascii_lowercase`](str::make_ascii_lowercase).
+    pub fn to_ascii_lowercase_into<const N: usize>(&self, out: &mut PushString<N>) -> Result<()> {
+        // Changing the case of ASCII letters keeps the bytes valid UTF-8
+        self.bytes.to_ascii_lowercase_into(&mut out.bytes)
+    }
+
+    /// Removes the last char of this string and returns it.
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        let new_len = self.len() - ch.len_utf8();
+
+        // Safety: `new_len` is smaller than the current length, and bytes are
+        //         trivially droppable. It's also a char boundary.
+        unsafe { self.bytes.set_len(new_len) };
+
+        Some(ch)
+    }
+
+    /// Inserts a string slice at the given byte position.
+    ///
+    /// If it does not fit, nothing is inserted and
+    /// [`Error::NotEnoughCapacity`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is past the end of this string, or not on a char
+    /// boundary.
+    ///
+    /// ```
+    /// use pushy::PushString;
+    ///
+    /// let mut line: PushString<16> = "ls /tmp".parse().unwrap();
+    /// line.insert_str(2, " -la").unwrap();
+    ///
+    /// assert_eq!(&*line, "ls -la /tmp");
+    /// ```
+    pub fn insert_str(&mut self, idx: usize, value: &str) -> Result<()> {
+        assert!(
+            self.is_char_boundary(idx),
+            "insertion index is not on a char boundary"
+        );
+
+        self.push_str(value)?;
+        self.bytes[idx..].rotate_right(value.len());
+
+        Ok(())
+    }
+
+    /// Inserts a char at the given byte position.
+    ///
+    /// If it does not fit, nothing is inserted and
+    /// [`Error::NotEnoughCapacity`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is past the end of this string, or not on a char
+    /// boundary.
+    pub fn insert(&mut self, idx: usize, ch: char) -> Result<()> {
+        self.insert_str(idx, ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Removes the char at the given byte position and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is not the position of a char of this string.
+    ///
+    /// ```
+    /// use pushy::PushString;
+    ///
+    /// let mut line: PushString<16> = "añb".parse().unwrap();
+    ///
+    /// assert_eq!(line.remove(1), 'ñ');
+    /// assert_eq!(&*line, "ab");
+    /// ```
+    pub fn remove(&mut self, idx: usize) -> char {
+        let ch = match self[idx..].chars().next() {
+            Some(ch) => ch,
+            None => panic!("cannot remove a char from the end of a string"),
+        };
+        self.remove_bytes(idx, idx + ch.len_utf8());
+
+        ch
+    }
+
+    /// Replaces the given byte range of this string with a string slice.
+    ///
+    /// If the result does not fit, nothing is replaced and
+    /// [`Error::NotEnoughCapacity`] is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or its ends aren't on char
+    /// boundaries.
+    ///
+    /// ```
+    /// use pushy::PushString;
+    ///
+    /// let mut line: PushString<16> = "cat notes.txt".parse().unwrap();
+    /// line.replace_range(..3, "less").unwrap();
+    ///
+    /// assert_eq!(&*line, "less notes.txt");
+    /// assert!(line.replace_range(5.., "a-very-long-name.txt").is_err());
+    /// ```
+    pub fn replace_range<R>(&mut self, range: R, value: &str) -> Result<()>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        // Checks the bounds and char boundaries
+        let replaced = self[start..end].len();
+
+        if self.len() - replaced + value.len() > CAP {
+            return Err(Error::NotEnoughCapacity);
+        }
+
+        self.remove_bytes(start, end);
+        self.insert_str(start, value)
+    }
+
+    /// Removes a range of bytes, which must be in-bounds and start and end at
+    /// char boundaries.
+    fn remove_bytes(&mut self, start: usize, end: usize) {
+        let new_len = self.len() - (end - start);
+        self.bytes[start..].rotate_left(end - start);
+
+        // Safety: `new_len` is smaller than the current length, and bytes are
+        //         trivially droppable. The removed bytes make up whole chars,
+        //         so the rest is still valid UTF-8.
+        unsafe { self.bytes.set_len(new_len) };
+    }
+
+    /// Removes the contents of this string.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+}
+
+impl<const CAP: usize> Deref for PushString<CAP> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> DerefMut for PushString<CAP> {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const CAP: usize> AsRef<str> for PushString<CAP> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> AsRef<[u8]> for PushString<CAP> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for PushString<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const CAP: usize> fmt::Display for PushString<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// A string that does not fit is not written at all, and `fmt::Error` is returned.
+impl<const CAP: usize> fmt::Write for PushString<CAP> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const CAP: usize> TryFrom<&str> for PushString<CAP> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let mut string = Self::new();
+        string.push_str(value)?;
+
+        Ok(string)
+    }
+}
+
+/// Fails with [`Error::NotEnoughCapacity`] if the string is longer than `CAP` bytes.
+///
+/// ```
+/// use pushy::PushString;
+///
+/// let name: PushString<8> = "sensor".parse().unwrap();
+/// assert_eq!(&*name, "sensor");
+///
+/// assert!("temperature".parse::<PushString<8>>().is_err());
+/// ```
+impl<const CAP: usize> FromStr for PushString<CAP> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::try_from(s)
+    }
+}
+
+/// Collects chars into a [`PushString`].
+///
+/// # Panics
+///
+/// Panics if the chars take more than `CAP` bytes. See
+/// [`PushString::try_from_chars`] for a fallible alternative.
+///
+/// Not available with the `no-panic-api` feature.
+///
+/// ```
+/// use pushy::PushString;
+///
+/// let input = "a-ñ-b";
+/// let ascii: PushString<8> = input.chars().filter(char::is_ascii_alphabetic).collect();
+///
+/// assert_eq!(&*ascii, "ab");
+/// ```
+#[cfg(not(feature = "no-panic-api"))]
+impl<const CAP: usize> FromIterator<char> for PushString<CAP> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        Self::try_from_chars(iter).unwrap_or_else(|_| capacity_overflow())
+    }
+}
+
+/// Concatenates string slices into a [`PushString`].
+///
+/// # Panics
+///
+/// Panics if the slices take more than `CAP` bytes. See
+/// [`PushString::try_from_strs`] for a fallible alternative.
+///
+/// Not available with the `no-panic-api` feature.
+#[cfg(not(feature = "no-panic-api"))]
+impl<'a, const CAP: usize> FromIterator<&'a str> for PushString<CAP> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        Self::try_from_strs(iter).unwrap_or_else(|_| capacity_overflow())
+    }
+}
+
+/// Collects chars into a [`PushString`], like [`PushString::try_from_chars`].
+impl<const CAP: usize> TryFromIterator<char> for PushString<CAP> {
+    fn try_from_iter<I: IntoIterator<Item = char>>(iter: I) -> Result<Self> {
+        Self::try_from_chars(iter)
+    }
+}
+
+/// Concatenates string slices into a [`PushString`], like
+/// [`PushString::try_from_strs`].
+impl<'a, const CAP: usize> TryFromIterator<&'a str> for PushString<CAP> {
+    fn try_from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Result<Self> {
+        Self::try_from_strs(iter)
+    }
+}
+
+impl<'a, const CAP: usize> IntoIterator for &'a PushString<CAP> {
+    type Item = char;
+    type IntoIter = Chars<'a>;
+
+    fn into_iter(self) -> Chars<'a> {
+        self.chars()
+    }
+}
+
+/// Iterates over the chars of the string.
+///
+/// ```
+/// use pushy::PushString;
+///
+/// let word: PushString<8> = "añb".parse().unwrap();
+/// let reversed: Vec<char> = word.into_iter().rev().collect();
+///
+/// assert_eq!(reversed, ['b', 'ñ', 'a']);
+/// ```
+impl<const CAP: usize> IntoIterator for PushString<CAP> {
+    type Item = char;
+    type IntoIter = IntoChars<CAP>;
+
+    fn into_iter(self) -> IntoChars<CAP> {
+        let end = self.len();
+
+        IntoChars {
+            string: self,
+            start: 0,
+            end,
+        }
+    }
+}
+
+/// An owning iterator over the chars of a [`PushString`].
+///
+/// Returned by [`PushString::into_iter`](IntoIterator::into_iter).
+#[derive(Debug, Clone)]
+pub struct IntoChars<const CAP: usize> {
+    string: PushString<CAP>,
+    /// Always at a char boundary
+    start: usize,
+    /// Always at a char boundary
+    end: usize,
+}
+
+impl<const CAP: usize> IntoChars<CAP> {
+    /// Returns the chars that have not been yielded yet.
+    pub fn as_str(&self) -> &str {
+        &self.string[self.start..self.end]
+    }
+}
+
+impl<const CAP: usize> Iterator for IntoChars<CAP> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next()?;
+        self.start += ch.len_utf8();
+
+        Some(ch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_str().chars().size_hint()
+    }
+}
+
+impl<const CAP: usize> DoubleEndedIterator for IntoChars<CAP> {
+    fn next_back(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.end -= ch.len_utf8();
+
+        Some(ch)
+    }
+}
+
+impl<const CAP: usize> FusedIterator for IntoChars<CAP> {}
+
+/// Appends chars to a [`PushString`].
+///
+/// # Panics
+///
+/// Panics if a char does not fit. The chars before it are kept.
+///
+/// Not available with the `no-panic-api` feature.
+///
+/// ```
+/// use pushy::PushString;
+///
+/// let mut slug: PushString<16> = "post-".parse().unwrap();
+/// slug.extend("Hello World".chars().filter(char::is_ascii_alphanumeric));
+///
+/// assert_eq!(&*slug, "post-HelloWorld");
+/// ```
+#[cfg(not(feature = "no-panic-api"))]
+impl<const CAP: usize> Extend<char> for PushString<CAP> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for ch in iter {
+            self.push_char(ch).unwrap_or_else(|_| capacity_overflow());
+        }
+    }
+}
+
+/// Appends string slices to a [`PushString`].
+///
+/// # Panics
+///
+/// Panics if a slice does not fit. The slices before it are kept.
+///
+/// Not available with the `no-panic-api` feature.
+#[cfg(not(feature = "no-panic-api"))]
+impl<'a, const CAP: usize> Extend<&'a str> for PushString<CAP> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for piece in iter {
+            self.push_str(piece).unwrap_or_else(|_| capacity_overflow());
+        }
+    }
+}
+
+/// Concatenates a string slice to a [`PushString`], like `String` does.
+///
+/// # Panics
+///
+/// Panics if the result does not fit. [`PushString::push_str`] is the
+/// fallible alternative.
+///
+/// Not available with the `no-panic-api` feature.
+///
+/// ```
+/// use pushy::PushString;
+///
+/// let base: PushString<16> = "/dev/".parse().unwrap();
+/// let path = base + "ttyUSB" + "0";
+///
+/// assert_eq!(&*path, "/dev/ttyUSB0");
+/// ```
+#[cfg(not(feature = "no-panic-api"))]
+impl<const CAP: usize> core::ops::Add<&str> for PushString<CAP> {
+    type Output = Self;
+
+    fn add(mut self, rhs: &str) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+/// Appends a string slice to a [`PushString`], like `String` does.
+///
+/// # Panics
+///
+/// Panics if the result does not fit. [`PushString::push_str`] is the
+/// fallible alternative.
+///
+/// Not available with the `no-panic-api` feature.
+#[cfg(not(feature = "no-panic-api"))]
+impl<const CAP: usize> core::ops::AddAssign<&str> for PushString<CAP> {
+    fn add_assign(&mut self, rhs: &str) {
+        self.push_str(rhs).unwrap_or_else(|_| capacity_overflow());
+    }
+}
+
+/// Implements equality between a [`PushString`] and string types, in both
+/// directions, like `String` does.
+macro_rules! impl_str_eq {
+    ($([$($generics:tt)*] $rhs:ty),* $(,)?) => {
+        $(
+            impl<$($generics)* const CAP: usize> PartialEq<$rhs> for PushString<CAP> {
+                fn eq(&self, other: &$rhs) -> bool {
+                    self.as_str() == &other[..]
+                }
+            }
+
+            impl<$($generics)* const CAP: usize> PartialEq<PushString<CAP>> for $rhs {
+                fn eq(&self, other: &PushString<CAP>) -> bool {
+                    &self[..] == other.as_str()
+                }
+            }
+        )*
+    };
+}
+
+impl_str_eq! {
+    [] str,
+    ['a,] &'a str,
+}
+
+#[cfg(feature = "alloc")]
+impl_str_eq! {
+    [] alloc::string::String,
+}
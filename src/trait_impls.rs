@@ -1,68 +1,226 @@
 use core::{
-    fmt::Debug,
+    borrow::{Borrow, BorrowMut},
+    fmt::{self, Debug},
     hash::Hash,
     ops::{Deref, DerefMut},
 };
 
-use crate::PushArray;
+#[cfg(not(feature = "no-panic-api"))]
+use crate::capacity_overflow;
+use crate::{Error, LenType, PushArray, TryFromIterator};
 
-impl<T: Clone, const CAP: usize> Clone for PushArray<T, CAP> {
+impl<T, const CAP: usize, L: LenType> Default for PushArray<T, CAP, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The elements cloned so far are dropped if cloning one of them panics.
+///
+/// [`clone_from`](Clone::clone_from) reuses the elements already in the
+/// destination, cloning into them in place, so that their resources can be
+/// kept.
+impl<T: Clone, const CAP: usize, L: LenType> Clone for PushArray<T, CAP, L> {
     fn clone(&self) -> Self {
-        self.iter().cloned().collect()
+        let mut arr = Self::new();
+        // `arr` has the same capacity as `self`, so all of its elements fit
+        arr.push_iter(self.iter().cloned());
+
+        arr
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        let common = self.len().min(source.len());
+
+        self.drop_tail(common);
+        self[..common].clone_from_slice(&source[..common]);
+        self.push_iter(source[common..].iter().cloned());
     }
 }
 
-impl<T: Hash, const CAP: usize> Hash for PushArray<T, CAP> {
+/// Hashes exactly like the equivalent `[T]`, so `PushArray` keys in maps can be
+/// looked up by slice through [`Borrow`].
+impl<T: Hash, const CAP: usize, L: LenType> Hash for PushArray<T, CAP, L> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.initialized().hash(state);
-        self.len.hash(state);
     }
 }
 
-impl<T, const CAP: usize> AsRef<[T]> for PushArray<T, CAP> {
+impl<T, const CAP: usize, L: LenType> Borrow<[T]> for PushArray<T, CAP, L> {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> BorrowMut<[T]> for PushArray<T, CAP, L> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> AsRef<[T]> for PushArray<T, CAP, L> {
     fn as_ref(&self) -> &[T] {
         self.as_slice()
     }
 }
 
-impl<T: PartialEq, const CAP: usize, U> PartialEq<U> for PushArray<T, CAP>
-where
-    U: AsRef<[T]>,
+impl<'a, T, const CAP: usize, const M: usize, L: LenType> TryFrom<&'a PushArray<T, CAP, L>>
+    for &'a [T; M]
 {
-    fn eq(&self, other: &U) -> bool {
-        self.as_ref() == other.as_ref()
+    type Error = Error;
+
+    fn try_from(value: &'a PushArray<T, CAP, L>) -> Result<Self, Self::Error> {
+        value.as_array().ok_or(Error::NotEnoughElements)
     }
 }
 
-impl<T: Eq, const CAP: usize> Eq for PushArray<T, CAP> {}
+impl<T: Copy, const CAP: usize, const M: usize, L: LenType> TryFrom<&PushArray<T, CAP, L>>
+    for [T; M]
+{
+    type Error = Error;
 
-impl<T: PartialOrd, const CAP: usize> PartialOrd for PushArray<T, CAP> {
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        self.initialized().partial_cmp(other.initialized())
+    fn try_from(value: &PushArray<T, CAP, L>) -> Result<Self, Self::Error> {
+        value.as_array().copied().ok_or(Error::NotEnoughElements)
     }
 }
 
-impl<T: Ord, const CAP: usize> Ord for PushArray<T, CAP> {
+/// Copies the bytes of the string. Fails with [`Error::NotEnoughCapacity`] if
+/// there are more than `CAP` of them.
+impl<const CAP: usize, L: LenType> TryFrom<&str> for PushArray<u8, CAP, L> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut bytes = Self::new();
+        bytes.push_str(value)?;
+
+        Ok(bytes)
+    }
+}
+
+/// Implements element-wise equality between a [`PushArray`] and slice-like
+/// types, in both directions, like `Vec` does.
+macro_rules! impl_partial_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $rhs:ty $(where $param:ident: $bound:path)?),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<$($generics)* T, U, const CAP: usize, L: LenType> PartialEq<$rhs>
+                for PushArray<T, CAP, L>
+            where
+                T: PartialEq<U>,
+                $($param: $bound,)?
+            {
+                fn eq(&self, other: &$rhs) -> bool {
+                    self[..] == other[..]
+                }
+            }
+
+            $(#[$attr])*
+            impl<$($generics)* T, U, const CAP: usize, L: LenType> PartialEq<PushArray<T, CAP, L>>
+                for $rhs
+            where
+                U: PartialEq<T>,
+                $($param: $bound,)?
+            {
+                fn eq(&self, other: &PushArray<T, CAP, L>) -> bool {
+                    self[..] == other[..]
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_eq! {
+    [] [U],
+    ['a,] &'a [U],
+    ['a,] &'a mut [U],
+    [const N: usize,] [U; N],
+    ['a, const N: usize,] &'a [U; N],
+    #[cfg(feature = "alloc")]
+    [] alloc::vec::Vec<U>,
+    #[cfg(feature = "alloc")]
+    [] alloc::boxed::Box<[U]>,
+    #[cfg(feature = "alloc")]
+    ['a,] alloc::borrow::Cow<'a, [U]> where U: Clone,
+}
+
+impl<T, U, const CAP: usize, const CAP2: usize, L: LenType, L2: LenType>
+    PartialEq<PushArray<U, CAP2, L2>> for PushArray<T, CAP, L>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &PushArray<U, CAP2, L2>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T: Eq, const CAP: usize, L: LenType> Eq for PushArray<T, CAP, L> {}
+
+/// Implements lexicographic ordering between a [`PushArray`] and slice-like types.
+macro_rules! impl_partial_ord {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $rhs:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl<$($generics)* T: PartialOrd, const CAP: usize, L: LenType> PartialOrd<$rhs>
+                for PushArray<T, CAP, L>
+            {
+                fn partial_cmp(&self, other: &$rhs) -> Option<core::cmp::Ordering> {
+                    self[..].partial_cmp(&other[..])
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_ord! {
+    [] [T],
+    ['a,] &'a [T],
+    [const N: usize,] [T; N],
+    ['a, const N: usize,] &'a [T; N],
+    [const CAP2: usize, L2: LenType,] PushArray<T, CAP2, L2>,
+    #[cfg(feature = "alloc")]
+    [] alloc::vec::Vec<T>,
+    #[cfg(feature = "alloc")]
+    [] alloc::boxed::Box<[T]>,
+}
+
+impl<T: Ord, const CAP: usize, L: LenType> Ord for PushArray<T, CAP, L> {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.initialized().cmp(other.initialized())
     }
 }
 
-impl<T: Debug, const CAP: usize> Debug for PushArray<T, CAP> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("PushArray")
-            .field("initialized", &self.initialized())
-            .finish()
+/// Formats the initialized elements like a slice, as `Vec` does.
+///
+/// The alternate form, `{:#?}`, also shows the length and capacity.
+impl<T: Debug, const CAP: usize, L: LenType> Debug for PushArray<T, CAP, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("PushArray")
+                .field("len", &self.len())
+                .field("capacity", &CAP)
+                .field("initialized", &self.initialized())
+                .finish()
+        } else {
+            self.initialized().fmt(f)
+        }
+    }
+}
+
+/// Lets byte buffers be the target of `write!`. A string that does not fit is
+/// not written at all, and `fmt::Error` is returned.
+impl<const CAP: usize, L: LenType> fmt::Write for PushArray<u8, CAP, L> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
     }
 }
 
-impl<T, const CAP: usize> Drop for PushArray<T, CAP> {
+impl<T, const CAP: usize, L: LenType> Drop for PushArray<T, CAP, L> {
     fn drop(&mut self) {
         self.clear()
     }
 }
 
-impl<T, const CAP: usize> Deref for PushArray<T, CAP> {
+impl<T, const CAP: usize, L: LenType> Deref for PushArray<T, CAP, L> {
     type Target = [T];
 
     fn deref(&self) -> &[T] {
@@ -70,20 +228,55 @@ impl<T, const CAP: usize> Deref for PushArray<T, CAP> {
     }
 }
 
-impl<T, const CAP: usize> DerefMut for PushArray<T, CAP> {
+impl<T, const CAP: usize, L: LenType> DerefMut for PushArray<T, CAP, L> {
     fn deref_mut(&mut self) -> &mut [T] {
         self.initialized_mut()
     }
 }
 
-impl<T, const CAP: usize> FromIterator<T> for PushArray<T, CAP> {
+/// Collects the elements of an iterator into a [`PushArray`].
+///
+/// # Panics
+///
+/// Panics if the iterator yields more than `CAP` elements.
+///
+/// Not available with the `no-panic-api` feature.
+///
+/// # Panic safety
+///
+/// The elements collected so far are dropped if the iterator panics, or if it
+/// yields too many elements.
+#[cfg(not(feature = "no-panic-api"))]
+impl<T, const CAP: usize, L: LenType> FromIterator<T> for PushArray<T, CAP, L> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+
+        // Fail fast if the iterator is known to not fit
+        let (lower_bound, _) = iter.size_hint();
+        if lower_bound > CAP {
+            capacity_overflow();
+        }
+
         let mut arr = Self::new();
+        arr.push_iter(&mut iter);
 
-        for item in iter {
-            arr.push(item);
+        if iter.next().is_some() {
+            capacity_overflow();
         }
 
         arr
     }
 }
+
+/// Fails with [`Error::NotEnoughCapacity`] if the iterator yields more than
+/// `CAP` elements.
+impl<T, const CAP: usize, L: LenType> TryFromIterator<T> for PushArray<T, CAP, L> {
+    fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> crate::Result<Self> {
+        let mut arr = Self::new();
+        for item in iter {
+            arr.push_checked(item)?;
+        }
+
+        Ok(arr)
+    }
+}
@@ -0,0 +1,85 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{LenType, PushArray};
+
+/// A guard over a [`PushArray`] that undoes everything pushed through it,
+/// unless [`commit`](Transaction::commit)ted.
+///
+/// The guard dereferences to the array, so any of its methods can be used.
+/// When dropped without a commit, including on an early return through `?`,
+/// the array is truncated back to the length it had when the transaction
+/// started, and the elements pushed since are dropped.
+///
+/// Only pushed elements are rolled back: elements that were already in the
+/// array and get removed or modified during the transaction stay that way.
+///
+/// Created by [`PushArray::transaction`].
+///
+/// ```
+/// # use pushy::PushArray;
+/// fn encode(out: &mut PushArray<u8, 8>, fields: &[&str]) -> pushy::Result<()> {
+///     let mut tx = out.transaction();
+///     for field in fields {
+///         tx.push_checked(field.len() as u8)?;
+///         tx.push_str(field)?;
+///     }
+///     tx.commit();
+///
+///     Ok(())
+/// }
+///
+/// let mut out = PushArray::new();
+/// encode(&mut out, &["ab"]).unwrap();
+///
+/// // The second field doesn't fit, so nothing of this frame is kept
+/// assert!(encode(&mut out, &["cd", "efgh"]).is_err());
+/// assert_eq!(out, [2, b'a', b'b']);
+/// ```
+pub struct Transaction<'a, T, const CAP: usize, L: LenType = usize> {
+    arr: &'a mut PushArray<T, CAP, L>,
+    /// The length of the array when the transaction started
+    start: usize,
+}
+
+impl<T, const CAP: usize, L: LenType> Transaction<'_, T, CAP, L> {
+    /// Keeps every element pushed during this transaction.
+    pub fn commit(mut self) {
+        self.start = self.arr.len();
+    }
+
+    /// Drops every element pushed during this transaction, without ending it.
+    pub fn rollback(&mut self) {
+        self.arr.drop_tail(self.start)
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> Deref for Transaction<'_, T, CAP, L> {
+    type Target = PushArray<T, CAP, L>;
+
+    fn deref(&self) -> &Self::Target {
+        self.arr
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> DerefMut for Transaction<'_, T, CAP, L> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.arr
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> Drop for Transaction<'_, T, CAP, L> {
+    fn drop(&mut self) {
+        self.rollback()
+    }
+}
+
+impl<T, const CAP: usize, L: LenType> PushArray<T, CAP, L> {
+    /// Starts a transaction, whose pushes are undone unless committed.
+    ///
+    /// See [`Transaction`] for details.
+    pub fn transaction(&mut self) -> Transaction<'_, T, CAP, L> {
+        let start = self.len();
+
+        Transaction { arr: self, start }
+    }
+}
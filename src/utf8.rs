@@ -0,0 +1,139 @@
+use crate::PushArray;
+
+/// Decodes UTF-8 text fed one byte at a time, such as bytes coming in from a
+/// serial port.
+///
+/// Partial sequences are buffered until they're complete. Invalid sequences
+/// are replaced by [`char::REPLACEMENT_CHARACTER`], the same way
+/// `String::from_utf8_lossy` does it.
+///
+/// ```
+/// use pushy::Utf8Decoder;
+///
+/// let mut decoder = Utf8Decoder::new();
+/// let mut text = String::new();
+///
+/// for byte in "olá, 🌍".bytes() {
+///     text.extend(decoder.push(byte).iter());
+/// }
+/// text.extend(decoder.finish());
+///
+/// assert_eq!(text, "olá, 🌍");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Utf8Decoder {
+    partial: PushArray<u8, 4>,
+    /// Total length of the sequence being decoded
+    needed: u8,
+    /// Bounds of the next continuation byte
+    lower: u8,
+    upper: u8,
+}
+
+impl Utf8Decoder {
+    /// Creates a new [`Utf8Decoder`].
+    pub const fn new() -> Self {
+        Self {
+            partial: PushArray::new(),
+            needed: 0,
+            lower: 0x80,
+            upper: 0xBF,
+        }
+    }
+
+    /// Returns true if the decoder holds the start of an incomplete sequence.
+    pub fn is_pending(&self) -> bool {
+        !self.partial.is_empty()
+    }
+
+    /// Feeds a byte to the decoder, returning the chars it completes.
+    ///
+    /// That is usually zero or one char, but it's two when the byte interrupts
+    /// an incomplete sequence, which gets replaced, and is a char by itself.
+    pub fn push(&mut self, byte: u8) -> PushArray<char, 2> {
+        let mut decoded = PushArray::new();
+
+        if self.is_pending() {
+            if !(self.lower..=self.upper).contains(&byte) {
+                // The pending sequence is invalid, and `byte` is the start of
+                // something new
+                self.reset();
+                Self::emit(&mut decoded, char::REPLACEMENT_CHARACTER);
+                if let Some(ch) = self.start(byte) {
+                    Self::emit(&mut decoded, ch);
+                }
+                return decoded;
+            }
+
+            self.push_partial(byte);
+            (self.lower, self.upper) = (0x80, 0xBF);
+
+            if self.partial.len() == usize::from(self.needed) {
+                // The bounds checked along the way ensure this is valid UTF-8
+                let ch = core::str::from_utf8(&self.partial)
+                    .ok()
+                    .and_then(|s| s.chars().next())
+                    .unwrap_or(char::REPLACEMENT_CHARACTER);
+                self.reset();
+                Self::emit(&mut decoded, ch);
+            }
+        } else if let Some(ch) = self.start(byte) {
+            Self::emit(&mut decoded, ch);
+        }
+
+        decoded
+    }
+
+    /// Signals the end of the input, returning a replacement char if an
+    /// incomplete sequence was pending.
+    pub fn finish(&mut self) -> Option<char> {
+        let was_pending = self.is_pending();
+        self.reset();
+
+        was_pending.then_some(char::REPLACEMENT_CHARACTER)
+    }
+
+    /// Handles the first byte of a sequence, returning a char if the byte is
+    /// a complete (or invalid) sequence by itself.
+    fn start(&mut self, byte: u8) -> Option<char> {
+        let (needed, lower, upper) = match byte {
+            0x00..=0x7F => return Some(char::from(byte)),
+            0xC2..=0xDF => (2, 0x80, 0xBF),
+            0xE0 => (3, 0xA0, 0xBF),
+            0xED => (3, 0x80, 0x9F),
+            0xE1..=0xEF => (3, 0x80, 0xBF),
+            0xF0 => (4, 0x90, 0xBF),
+            0xF4 => (4, 0x80, 0x8F),
+            0xF1..=0xF3 => (4, 0x80, 0xBF),
+            _ => return Some(char::REPLACEMENT_CHARACTER),
+        };
+
+        self.push_partial(byte);
+        (self.needed, self.lower, self.upper) = (needed, lower, upper);
+
+        None
+    }
+
+    fn push_partial(&mut self, byte: u8) {
+        // Sequences are at most four bytes long, and are taken out as soon as
+        // they're complete
+        debug_assert!(self.partial.len() < 4);
+        let _ = self.partial.push_checked(byte);
+    }
+
+    fn emit(decoded: &mut PushArray<char, 2>, ch: char) {
+        // At most two chars are emitted per byte
+        let _ = decoded.push_checked(ch);
+    }
+
+    fn reset(&mut self) {
+        self.partial.clear();
+        (self.needed, self.lower, self.upper) = (0, 0x80, 0xBF);
+    }
+}
+
+impl Default for Utf8Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
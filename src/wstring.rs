@@ -0,0 +1,193 @@
+use core::{
+    char::DecodeUtf16,
+    fmt::{self, Write},
+    iter::Copied,
+    slice,
+};
+
+use crate::{not_enough_capacity, Error, PushArray, Result, TryFromIterator};
+
+/// A fixed-capacity, stack-allocated UTF-16 string.
+///
+/// Holds up to `CAP` UTF-16 code units, transcoded from UTF-8 as text is
+/// pushed. Meant for interfaces that expect UTF-16, such as Windows APIs and
+/// USB string descriptors.
+///
+/// ```
+/// use pushy::PushWString;
+///
+/// let mut product: PushWString<16> = PushWString::new();
+/// product.push_str("Sensör").unwrap();
+///
+/// assert_eq!(product.as_wide(), &[0x53, 0x65, 0x6e, 0x73, 0xf6, 0x72]);
+///
+/// // A NUL-terminated view for FFI, without changing the contents
+/// assert_eq!(product.as_nul_terminated().unwrap().last(), Some(&0));
+/// assert_eq!(product.len(), 6);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PushWString<const CAP: usize> {
+    units: PushArray<u16, CAP>,
+}
+
+impl<const CAP: usize> PushWString<CAP> {
+    /// Creates an empty [`PushWString`].
+    pub const fn new() -> Self {
+        Self {
+            units: PushArray::new(),
+        }
+    }
+
+    /// Returns the length of this string, in UTF-16 code units.
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    /// Returns true if this string has no contents.
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// Returns the UTF-16 code units of this string.
+    pub fn as_wide(&self) -> &[u16] {
+        &self.units
+    }
+
+    /// Returns the code units of this string followed by a NUL, as expected
+    /// by C and Windows APIs.
+    ///
+    /// The NUL is written past the end of the string and isn't counted in its
+    /// length, so the string is left unchanged. Fails with
+    /// [`Error::NotEnoughCapacity`] if there's no room for it.
+    pub fn as_nul_terminated(&mut self) -> Result<&[u16]> {
+        let len = self.len();
+        let nul = self
+            .units
+            .spare_capacity_mut()
+            .first_mut()
+            .ok_or_else(not_enough_capacity)?;
+        nul.write(0);
+
+        // Safety: the first `len` units are initialized, and the NUL after them
+        //         was just written
+        Ok(unsafe { slice::from_raw_parts(self.units.as_ptr(), len + 1) })
+    }
+
+    /// Transcodes a string slice to UTF-16 and pushes it to the end of this
+    /// string.
+    ///
+    /// If it does not fit, nothing is pushed and [`Error::NotEnoughCapacity`]
+    /// is returned.
+    pub fn push_str(&mut self, value: &str) -> Result<()> {
+        self.units
+            .ensure_capacity_for(value.encode_utf16().count())?;
+
+        for unit in value.encode_utf16() {
+            // Safety: we've just checked that every unit fits
+            unsafe { self.units.push_unchecked(unit) };
+        }
+
+        Ok(())
+    }
+
+    /// Pushes a char to the end of this string.
+    ///
+    /// If it does not fit, nothing is pushed and [`Error::NotEnoughCapacity`]
+    /// is returned.
+    pub fn push_char(&mut self, ch: char) -> Result<()> {
+        self.units.copy_from_slice(ch.encode_utf16(&mut [0; 2]))
+    }
+
+    /// Returns an iterator over the chars of this string.
+    pub fn chars(&self) -> Chars<'_> {
+        Chars {
+            inner: char::decode_utf16(self.units.iter().copied()),
+        }
+    }
+
+    /// Empties this string.
+    pub fn clear(&mut self) {
+        self.units.clear()
+    }
+}
+
+/// An iterator over the chars of a [`PushWString`].
+///
+/// Returned by [`PushWString::chars`].
+#[derive(Debug, Clone)]
+pub struct Chars<'a> {
+    inner: DecodeUtf16<Copied<slice::Iter<'a, u16>>>,
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        // A `PushWString` is only ever built from chars, so its contents are
+        // always valid UTF-16
+        self.inner
+            .next()
+            .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<const CAP: usize> AsRef<[u16]> for PushWString<CAP> {
+    fn as_ref(&self) -> &[u16] {
+        self.as_wide()
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for PushWString<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for ch in self.chars() {
+            for escaped in ch.escape_debug() {
+                f.write_char(escaped)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl<const CAP: usize> fmt::Display for PushWString<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|ch| f.write_char(ch))
+    }
+}
+
+/// A string that does not fit is not written at all, and `fmt::Error` is returned.
+impl<const CAP: usize> fmt::Write for PushWString<CAP> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+/// Fails with [`Error::NotEnoughCapacity`] if the string takes more than `CAP`
+/// UTF-16 code units.
+impl<const CAP: usize> TryFrom<&str> for PushWString<CAP> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let mut string = Self::new();
+        string.push_str(value)?;
+
+        Ok(string)
+    }
+}
+
+/// Collects chars into a [`PushWString`], failing with
+/// [`Error::NotEnoughCapacity`] if they take more than `CAP` UTF-16 code units.
+impl<const CAP: usize> TryFromIterator<char> for PushWString<CAP> {
+    fn try_from_iter<I: IntoIterator<Item = char>>(iter: I) -> Result<Self> {
+        let mut string = Self::new();
+        for ch in iter {
+            string.push_char(ch)?;
+        }
+
+        Ok(string)
+    }
+}
@@ -0,0 +1,30 @@
+use pushy::align::{Align16, Align4, Align64, AlignedPushArray};
+
+#[test]
+fn storage_is_aligned() {
+    let bytes: AlignedPushArray<u8, 7, Align64, u8> = AlignedPushArray::new();
+    assert_eq!(bytes.as_ptr() as usize % 64, 0);
+    assert_eq!(core::mem::align_of_val(&bytes), 64);
+
+    // Also within arrays and structs
+    let many: [AlignedPushArray<u8, 3, Align16>; 3] = Default::default();
+    for arr in &many {
+        assert_eq!(arr.as_ptr() as usize % 16, 0);
+    }
+
+    // Elements already aligned beyond `A` keep their own alignment
+    let wide: AlignedPushArray<u64, 2, Align4> = AlignedPushArray::new();
+    assert_eq!(core::mem::align_of_val(&wide), core::mem::align_of::<u64>());
+}
+
+#[test]
+fn derefs_to_push_array() {
+    let mut arr: AlignedPushArray<u32, 4, Align16> = AlignedPushArray::new();
+    arr.copy_from_slice(&[1, 2, 3]).unwrap();
+    arr[0] = 10;
+    assert_eq!(arr.pop(), Some(3));
+
+    let inner = arr.clone().into_inner();
+    assert_eq!(inner, [10, 2]);
+    assert_eq!(*arr, inner);
+}
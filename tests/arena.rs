@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use pushy::GenArena;
+
+#[test]
+fn arena_detects_stale_keys() {
+    let mut arena: GenArena<u32, 2> = GenArena::new();
+    let a = arena.insert(1).unwrap();
+    let b = arena.insert(2).unwrap();
+    assert!(arena.insert(3).is_err());
+
+    assert_eq!(arena.remove(a), Some(1));
+    assert_eq!(arena.remove(a), None);
+
+    let c = arena.insert(3).unwrap();
+    assert_eq!(c.index(), a.index());
+    assert_eq!(c.generation(), a.generation() + 1);
+    assert!(!arena.contains(a));
+    assert_eq!(arena.get_mut(a), None);
+    assert_eq!(arena.get(c), Some(&3));
+
+    *arena.get_mut(b).unwrap() += 10;
+    let collected: Vec<_> = arena.iter().collect();
+    assert_eq!(collected, [(c, &3), (b, &12)]);
+}
+
+#[test]
+fn arena_clear_invalidates_keys() {
+    let value = Arc::new(());
+    let mut arena: GenArena<Arc<()>, 3> = GenArena::new();
+    let a = arena.insert(value.clone()).unwrap();
+    let b = arena.insert(value.clone()).unwrap();
+    assert_eq!(Arc::strong_count(&value), 3);
+
+    arena.clear();
+    assert!(arena.is_empty());
+    assert_eq!(Arc::strong_count(&value), 1);
+
+    // Slots are reused in index order, with fresh generations
+    let c = arena.insert(value.clone()).unwrap();
+    let d = arena.insert(value.clone()).unwrap();
+    let e = arena.insert(value.clone()).unwrap();
+    assert_eq!((c.index(), d.index(), e.index()), (0, 1, 2));
+    assert!(!arena.contains(a) && !arena.contains(b));
+    assert!(arena.is_full());
+
+    drop(arena);
+    assert_eq!(Arc::strong_count(&value), 1);
+}
@@ -0,0 +1,38 @@
+use pushy::PushArray;
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn crc_append_and_verify() {
+    let mut frame: PushArray<u8, 16> = PushArray::new();
+    frame.copy_from_slice(&[0x7e, 0x01, 0x02]).unwrap();
+    frame.push_crc16_ccitt().unwrap();
+    assert!(frame.verify_crc16_ccitt());
+
+    // Any corruption is caught
+    frame[1] ^= 0x40;
+    assert!(!frame.verify_crc16_ccitt());
+    frame[1] ^= 0x40;
+
+    frame.push_crc32().unwrap();
+    assert!(frame.verify_crc32());
+    assert_eq!(frame.len(), 9);
+
+    let mut short: PushArray<u8, 3> = PushArray::new();
+    assert!(!short.verify_crc16_ccitt());
+    assert!(!short.verify_crc32());
+    short.push(1);
+    short.push(2);
+    assert!(short.push_crc16_ccitt().is_err());
+    assert_eq!(short, [1, 2]);
+}
+
+#[test]
+fn crc_of_empty_buffer() {
+    let mut frame: PushArray<u8, 4> = PushArray::new();
+    frame.push_crc16_ccitt().unwrap();
+    assert_eq!(frame, [0xFF, 0xFF]);
+
+    let mut frame: PushArray<u8, 4> = PushArray::new();
+    frame.push_crc32().unwrap();
+    assert_eq!(frame, [0, 0, 0, 0]);
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use pushy::PushSoA;
+
+#[derive(Debug, PartialEq, PushSoA)]
+struct Particle {
+    position: [f32; 2],
+    /// Doc comments and attributes on fields are fine
+    pub velocity: [f32; 2],
+    mass: f32,
+}
+
+#[derive(PushSoA)]
+pub(crate) struct Tricky {
+    map: HashMap<u8, u16>,
+    callback: fn(u8) -> u8,
+}
+
+#[test]
+fn soa_push_pop() {
+    let mut particles: ParticleSoA<2> = ParticleSoA::new();
+    assert!(particles.is_empty());
+
+    particles
+        .push(Particle {
+            position: [0.0, 1.0],
+            velocity: [1.0, 0.0],
+            mass: 2.0,
+        })
+        .unwrap();
+    particles
+        .push(Particle {
+            position: [5.0, 5.0],
+            velocity: [0.0, -1.0],
+            mass: 1.0,
+        })
+        .unwrap();
+    assert!(particles
+        .push(Particle {
+            position: [0.0; 2],
+            velocity: [0.0; 2],
+            mass: 0.0,
+        })
+        .is_err());
+
+    assert_eq!(particles.len(), 2);
+    assert_eq!(particles.mass(), [2.0, 1.0]);
+    assert_eq!(particles.position(), [[0.0, 1.0], [5.0, 5.0]]);
+
+    for particle in particles.iter_mut() {
+        particle.position[0] += particle.velocity[0];
+        particle.position[1] += particle.velocity[1];
+    }
+    particles.mass_mut()[1] = 3.0;
+
+    let momenta: Vec<f32> = particles
+        .iter()
+        .map(|particle| particle.velocity[0] * particle.mass)
+        .collect();
+    assert_eq!(momenta, [2.0, 0.0]);
+
+    assert_eq!(
+        particles.pop(),
+        Some(Particle {
+            position: [5.0, 4.0],
+            velocity: [0.0, -1.0],
+            mass: 3.0,
+        })
+    );
+    particles.clear();
+    assert_eq!(particles.pop(), None);
+}
+
+#[test]
+fn soa_types_with_commas_and_arrows() {
+    let mut tricky: TrickySoA<1> = TrickySoA::default();
+    tricky
+        .push(Tricky {
+            map: HashMap::from([(1, 2)]),
+            callback: |x| x + 1,
+        })
+        .unwrap();
+
+    let first = tricky.iter().next().unwrap();
+    assert_eq!(first.map[&1], 2);
+    assert_eq!((first.callback)(1), 2);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "generic_const_exprs")]
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use std::sync::Arc;
+
+use pushy::PushArray;
+
+#[test]
+fn concat_and_split_round_trip() {
+    let a: PushArray<u16, 3> = [1, 2].into_iter().collect();
+    let b: PushArray<u16, 5> = [3, 4, 5, 6, 7].into_iter().collect();
+
+    let joined: PushArray<u16, 8> = a.concat_exact(b);
+    assert_eq!(joined, [1, 2, 3, 4, 5, 6, 7]);
+
+    let (head, tail): (PushArray<u16, 3>, PushArray<u16, 5>) = joined.split_exact();
+    assert_eq!(head, [1, 2, 3]);
+    assert_eq!(tail, [4, 5, 6, 7]);
+
+    // Splitting past the initialized elements leaves the right side empty
+    let short: PushArray<u16, 4, u8> = [9].into_iter().collect();
+    let (left, right): (PushArray<u16, 2, u8>, PushArray<u16, 2, u8>) = short.split_exact();
+    assert_eq!(left, [9]);
+    assert!(right.is_empty());
+
+    let empty: PushArray<u8, 0> = PushArray::<u8, 0>::new().concat_exact(PushArray::<u8, 0>::new());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn concat_and_split_move_elements() {
+    let arc = Arc::new(());
+    let a: PushArray<Arc<()>, 2> = [arc.clone()].into_iter().collect();
+    let b: PushArray<Arc<()>, 2> = [arc.clone(), arc.clone()].into_iter().collect();
+
+    let joined = a.concat_exact(b);
+    assert_eq!(Arc::strong_count(&arc), 4);
+
+    let (left, right): (PushArray<_, 1>, PushArray<_, 3>) = joined.split_exact();
+    assert_eq!((left.len(), right.len()), (1, 2));
+    assert_eq!(Arc::strong_count(&arc), 4);
+
+    drop((left, right));
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use pushy::{GapBuffer, PushArray};
+
+fn text<const CAP: usize>(buffer: &GapBuffer<char, CAP>) -> String {
+    buffer.iter().collect()
+}
+
+#[test]
+fn edit_at_cursor() {
+    let mut line: GapBuffer<char, 8> = GapBuffer::new();
+    assert!(line.is_empty());
+    assert_eq!(line.remove_before(), None);
+    assert_eq!(line.remove_after(), None);
+
+    for ch in "wrld".chars() {
+        line.insert(ch).unwrap();
+    }
+    assert_eq!(line.cursor(), 4);
+
+    line.set_cursor(1);
+    line.insert('o').unwrap();
+    assert_eq!(text(&line), "world");
+    assert_eq!(line.as_slices(), (&['w', 'o'][..], &['r', 'l', 'd'][..]));
+
+    assert!(line.move_left());
+    assert_eq!(line.remove_after(), Some('o'));
+    assert!(line.move_right());
+    assert!(line.move_right());
+    assert_eq!(line.remove_before(), Some('l'));
+    assert_eq!(text(&line), "wrd");
+    assert_eq!(line.cursor(), 2);
+
+    line.set_cursor(0);
+    assert!(!line.move_left());
+    for ch in "hello".chars().rev() {
+        line.insert(ch).unwrap();
+        line.set_cursor(0);
+    }
+    assert!(line.is_full());
+    assert!(line.insert('!').is_err());
+    assert_eq!(text(&line), "hellowrd");
+
+    line.set_cursor(line.len());
+    assert!(!line.move_right());
+    assert_eq!(line.get(0), Some(&'h'));
+    assert_eq!(line.get(7), Some(&'d'));
+    assert_eq!(line.get(8), None);
+
+    line.set_cursor(5);
+    line.as_mut_slices().1.fill('-');
+    let arr: PushArray<char, 8> = line.into();
+    assert_eq!(arr.iter().collect::<String>(), "hello---");
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn conversions_keep_elements() {
+    let arr: PushArray<u32, 6> = [1, 2, 3, 4].into_iter().collect();
+    let mut buffer = GapBuffer::from(arr);
+    assert_eq!(buffer.cursor(), 4);
+
+    buffer.set_cursor(1);
+    buffer.insert(10).unwrap();
+    let copy = buffer.clone();
+    assert_eq!(copy, buffer);
+    assert_eq!(copy.cursor(), 2);
+    assert_eq!(format!("{copy:?}"), "[1, 10, 2, 3, 4]");
+
+    let arr: PushArray<u32, 6, u8> = buffer.into_push_array();
+    assert_eq!(arr, [1, 10, 2, 3, 4]);
+
+    let empty: GapBuffer<u32, 0> = GapBuffer::default();
+    assert!(empty.is_full());
+    assert_eq!(empty.into_push_array::<usize>().len(), 0);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+#[should_panic(expected = "cursor position out of bounds")]
+fn cursor_past_the_end() {
+    let mut buffer: GapBuffer<u8, 4> = [1, 2].into_iter().collect();
+    buffer.set_cursor(3);
+}
+
+#[test]
+fn gap_buffer_drops_elements() {
+    let arc = Arc::new(());
+    {
+        let mut buffer: GapBuffer<Arc<()>, 8> = GapBuffer::new();
+        for _ in 0..5 {
+            buffer.insert(arc.clone()).unwrap();
+        }
+        buffer.set_cursor(2);
+        drop(buffer.remove_after());
+        assert_eq!(Arc::strong_count(&arc), 5);
+
+        let arr: PushArray<Arc<()>, 8> = buffer.into();
+        assert_eq!(arr.len(), 4);
+        assert_eq!(Arc::strong_count(&arc), 5);
+
+        let mut buffer = GapBuffer::from(arr);
+        buffer.set_cursor(1);
+    }
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
@@ -0,0 +1,70 @@
+use pushy::{FixedIndexMap, TryFromIterator};
+
+#[test]
+fn index_map_keeps_insertion_order() {
+    let mut map: FixedIndexMap<u32, char, 4> = FixedIndexMap::new();
+    for (key, value) in [(30, 'c'), (10, 'a'), (40, 'd'), (20, 'b')] {
+        assert_eq!(map.insert(key, value).unwrap(), None);
+    }
+    assert!(map.is_full());
+    assert!(map.insert(50, 'e').is_err());
+    // Replacing doesn't need room, and keeps the position
+    assert_eq!(map.insert(10, 'A').unwrap(), Some('a'));
+
+    let entries: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(entries, [(30, 'c'), (10, 'A'), (40, 'd'), (20, 'b')]);
+    assert_eq!(map.get_index_of(&40), Some(2));
+    assert_eq!(map.get_index(1), Some((&10, &'A')));
+    assert_eq!(map.get_index(4), None);
+    assert_eq!(map.first(), Some((&30, &'c')));
+    assert_eq!(map.last(), Some((&20, &'b')));
+
+    *map.get_index_mut(0).unwrap().1 = 'C';
+    *map.get_mut(&20).unwrap() = 'B';
+    for (_, value) in map.iter_mut() {
+        *value = value.to_ascii_lowercase();
+    }
+    let values: String = map.values().collect();
+    assert_eq!(values, "cadb");
+    assert!(map.contains_key(&30));
+    assert_eq!(map.get(&25), None);
+}
+
+#[test]
+fn index_map_removals() {
+    let mut map: FixedIndexMap<String, usize, 5> =
+        FixedIndexMap::try_from_iter(["a", "b", "c", "d", "e"].map(|k| (k.to_owned(), k.len())))
+            .unwrap();
+
+    assert_eq!(map.shift_remove("b"), Some(1));
+    assert_eq!(map.shift_remove("b"), None);
+    let keys: Vec<_> = map.keys().map(String::as_str).collect();
+    assert_eq!(keys, ["a", "c", "d", "e"]);
+
+    assert_eq!(map.swap_remove("a"), Some(1));
+    let keys: Vec<_> = map.keys().map(String::as_str).collect();
+    assert_eq!(keys, ["e", "c", "d"]);
+
+    assert_eq!(map.shift_remove_index(3), None);
+    assert_eq!(map.swap_remove_index(3), None);
+    assert_eq!(map.swap_remove_index(2), Some(("d".to_owned(), 1)));
+    assert_eq!(map.shift_remove_index(0), Some(("e".to_owned(), 1)));
+    assert_eq!(map.pop(), Some(("c".to_owned(), 1)));
+    assert!(map.is_empty());
+    assert_eq!(map.pop(), None);
+    assert_eq!(map.swap_remove_index(0), None);
+
+    map.insert("z".to_owned(), 0).unwrap();
+    map.clear();
+    assert_eq!(map, FixedIndexMap::default());
+}
+
+#[test]
+fn index_map_equality_depends_on_order() {
+    let a: FixedIndexMap<u8, u8, 2> = FixedIndexMap::try_from_iter([(1, 1), (2, 2)]).unwrap();
+    let b: FixedIndexMap<u8, u8, 2> = FixedIndexMap::try_from_iter([(2, 2), (1, 1)]).unwrap();
+    assert_ne!(a, b);
+    assert_eq!(a.clone(), a);
+
+    assert!(FixedIndexMap::<u8, u8, 1>::try_from_iter([(1, 1), (2, 2)]).is_err());
+}
@@ -0,0 +1,43 @@
+use pushy::Interner;
+
+#[test]
+fn interner_deduplicates() {
+    let mut interner: Interner<16, 4> = Interner::new();
+    assert!(interner.is_empty());
+
+    let foo = interner.intern("foo").unwrap();
+    let empty = interner.intern("").unwrap();
+    let bar = interner.intern("bär").unwrap();
+    assert_eq!(interner.intern("foo").unwrap(), foo);
+    assert_eq!(interner.intern("").unwrap(), empty);
+    assert_eq!(interner.len(), 3);
+
+    assert_eq!(interner.resolve(foo), Some("foo"));
+    assert_eq!(interner.resolve(empty), Some(""));
+    assert_eq!(interner.resolve(bar), Some("bär"));
+    assert_eq!(interner.get("bär"), Some(bar));
+    assert_eq!(interner.get("baz"), None);
+
+    let collected: Vec<_> = interner.iter().collect();
+    assert_eq!(collected, [(foo, "foo"), (empty, ""), (bar, "bär")]);
+}
+
+#[test]
+fn interner_is_unchanged_when_full() {
+    let mut interner: Interner<8, 2> = Interner::new();
+    let hello = interner.intern("hello").unwrap();
+
+    // Not enough bytes
+    assert!(interner.intern("world").is_err());
+    let hi = interner.intern("hi").unwrap();
+    // Not enough symbols
+    assert!(interner.intern("!").is_err());
+    assert_eq!(interner.len(), 2);
+
+    // Already-interned strings are still found
+    assert_eq!(interner.intern("hello").unwrap(), hello);
+
+    interner.clear();
+    assert_eq!(interner.resolve(hi), None);
+    assert_eq!(interner.intern("world").unwrap().index(), 0);
+}
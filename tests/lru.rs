@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use pushy::LruCache;
+
+#[test]
+fn lru_evicts_least_recently_used() {
+    let mut cache: LruCache<&str, u32, 3> = LruCache::new();
+    assert_eq!(cache.insert("a", 1), None);
+    assert_eq!(cache.insert("b", 2), None);
+    assert_eq!(cache.insert("c", 3), None);
+    assert!(cache.is_full());
+
+    // Peeking doesn't count as a use
+    assert_eq!(cache.peek("a"), Some(&1));
+    *cache.get_mut("b").unwrap() += 10;
+
+    assert_eq!(cache.insert("d", 4), Some(("a", 1)));
+    // Replacing an entry hands back the old one and doesn't evict anything
+    assert_eq!(cache.insert("c", 30), Some(("c", 3)));
+
+    let entries: Vec<_> = cache.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(entries, [("c", 30), ("d", 4), ("b", 12)]);
+
+    assert_eq!(cache.remove("d"), Some(4));
+    assert_eq!(cache.remove("d"), None);
+    assert_eq!(cache.pop_lru(), Some(("b", 12)));
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn lru_zero_capacity() {
+    let mut cache: LruCache<u8, u8, 0> = LruCache::new();
+    assert_eq!(cache.insert(1, 2), Some((1, 2)));
+    assert!(cache.is_empty());
+    assert_eq!(cache.pop_lru(), None);
+}
+
+#[test]
+fn lru_drops_entries() {
+    let value = Arc::new(());
+    let mut cache: LruCache<u8, Arc<()>, 2> = LruCache::new();
+    for key in 0..5 {
+        drop(cache.insert(key, value.clone()));
+    }
+    assert_eq!(Arc::strong_count(&value), 3);
+
+    cache.clear();
+    assert_eq!(Arc::strong_count(&value), 1);
+}
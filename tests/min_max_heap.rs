@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use pushy::{MinMaxHeap, TryFromIterator};
+
+/// A small deterministic pseudo-random sequence, with plenty of duplicates
+fn samples(count: usize) -> impl Iterator<Item = u32> {
+    let mut state = 0x2545_f491_u32;
+    (0..count).map(move |_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state % 50
+    })
+}
+
+#[test]
+fn min_max_heap_pops_from_both_ends() {
+    let mut heap: MinMaxHeap<u32, 64> = MinMaxHeap::new();
+    assert_eq!((heap.peek_min(), heap.peek_max()), (None, None));
+    assert_eq!((heap.pop_min(), heap.pop_max()), (None, None));
+
+    let mut expected: Vec<u32> = samples(64).collect();
+    for value in expected.iter().copied() {
+        heap.push(value).unwrap();
+    }
+    assert!(heap.is_full());
+    assert!(heap.push(0).is_err());
+    expected.sort_unstable();
+
+    // Alternate between both ends, checking the peeks along the way
+    let mut from_front = true;
+    while !expected.is_empty() {
+        assert_eq!(heap.peek_min(), expected.first());
+        assert_eq!(heap.peek_max(), expected.last());
+        if from_front {
+            assert_eq!(heap.pop_min(), Some(expected.remove(0)));
+        } else {
+            assert_eq!(heap.pop_max(), expected.pop());
+        }
+        from_front = !from_front;
+        assert_eq!(heap.len(), expected.len());
+    }
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn min_max_heap_keeps_best_elements() {
+    let mut greatest: MinMaxHeap<u32, 8> = MinMaxHeap::new();
+    let mut smallest: MinMaxHeap<u32, 8> = MinMaxHeap::default();
+    for value in samples(200) {
+        greatest.push_evicting_min(value);
+        smallest.push_evicting_max(value);
+    }
+
+    let mut all: Vec<u32> = samples(200).collect();
+    all.sort_unstable();
+    assert_eq!(smallest.into_sorted(), all[..8]);
+    assert_eq!(greatest.into_sorted(), all[all.len() - 8..]);
+
+    // Values that wouldn't be kept are given back
+    let mut heap: MinMaxHeap<u32, 2> = MinMaxHeap::try_from_iter([5, 10]).unwrap();
+    assert_eq!(heap.push_evicting_min(5), Some(5));
+    assert_eq!(heap.push_evicting_min(7), Some(5));
+    assert_eq!(heap.push_evicting_max(10), Some(10));
+    assert_eq!(heap.push_evicting_max(8), Some(10));
+    let mut left: Vec<_> = heap.iter().copied().collect();
+    left.sort_unstable();
+    assert_eq!(left, [7, 8]);
+
+    let mut empty: MinMaxHeap<u32, 0> = MinMaxHeap::new();
+    assert_eq!(empty.push_evicting_min(1), Some(1));
+    assert_eq!(empty.push_evicting_max(1), Some(1));
+    assert!(MinMaxHeap::<u32, 1>::try_from_iter([1, 2]).is_err());
+}
+
+#[test]
+fn min_max_heap_drops_elements() {
+    let arc = Arc::new(());
+    let mut heap: MinMaxHeap<(u8, Arc<()>), 3> = MinMaxHeap::new();
+    for key in 0..5 {
+        drop(heap.push_evicting_min((key, arc.clone())));
+    }
+    assert_eq!(Arc::strong_count(&arc), 4);
+    assert_eq!(heap.pop_max().map(|(key, _)| key), Some(4));
+    assert_eq!(Arc::strong_count(&arc), 3);
+
+    heap.clear();
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use pushy::PushSlab;
+
+#[test]
+fn slab_insert_get_remove() {
+    let mut slab: PushSlab<u32, 3> = PushSlab::new();
+    assert!(slab.is_empty());
+
+    let a = slab.insert(10).unwrap();
+    let b = slab.insert(20).unwrap();
+    let c = slab.insert(30).unwrap();
+    assert!(slab.is_full());
+    assert!(slab.insert(40).is_err());
+
+    assert_eq!(slab.remove(b), Some(20));
+    assert_eq!(slab.remove(b), None);
+    assert_eq!(slab.get(b), None);
+    assert_eq!(slab.len(), 2);
+
+    *slab.get_mut(c).unwrap() += 1;
+    assert_eq!(slab.get(a), Some(&10));
+    assert_eq!(slab.get(c), Some(&31));
+    assert_eq!(slab.get(3), None);
+    assert_eq!(slab.remove(3), None);
+
+    let collected: Vec<_> = slab.iter().collect();
+    assert_eq!(collected, [(a, &10), (c, &31)]);
+}
+
+#[test]
+fn slab_reuses_freed_keys() {
+    let mut slab: PushSlab<char, 4> = PushSlab::new();
+    let keys: Vec<_> = "abcd".chars().map(|ch| slab.insert(ch).unwrap()).collect();
+
+    slab.remove(keys[1]);
+    slab.remove(keys[3]);
+
+    // Most recently freed slot comes first
+    assert_eq!(slab.insert('x').unwrap(), keys[3]);
+    assert_eq!(slab.insert('y').unwrap(), keys[1]);
+    assert!(slab.insert('z').is_err());
+
+    slab.clear();
+    assert!(slab.is_empty());
+    assert_eq!(slab.insert('a').unwrap(), 0);
+}
+
+#[test]
+fn slab_drops_values() {
+    let value = Arc::new(());
+    let mut slab: PushSlab<Arc<()>, 4> = PushSlab::new();
+    let key = slab.insert(value.clone()).unwrap();
+    slab.insert(value.clone()).unwrap();
+    assert_eq!(Arc::strong_count(&value), 3);
+
+    drop(slab.remove(key));
+    assert_eq!(Arc::strong_count(&value), 2);
+
+    drop(slab);
+    assert_eq!(Arc::strong_count(&value), 1);
+}
@@ -0,0 +1,64 @@
+use core::mem::MaybeUninit;
+#[cfg(not(feature = "no-panic-api"))]
+use std::rc::Rc;
+
+use pushy::PushSlice;
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn push_and_pop() {
+    let mut storage = [const { MaybeUninit::uninit() }; 3];
+    let mut arr = PushSlice::new(&mut storage);
+    assert!(arr.is_empty());
+
+    arr.push_checked(1).unwrap();
+    arr.push(2);
+    arr.copy_from_slice(&[3]).unwrap();
+    assert!(arr.is_full());
+    assert!(arr.push_checked(4).is_err());
+    assert!(arr.copy_from_slice(&[]).is_ok());
+
+    arr[0] = 10;
+    assert_eq!(&*arr, &[10, 2, 3]);
+    assert_eq!(arr.pop(), Some(3));
+    assert_eq!(arr.len(), 2);
+
+    arr.clear();
+    assert_eq!(arr.pop(), None);
+}
+
+#[test]
+fn runtime_capacity() {
+    fn fill(out: &mut PushSlice<'_, usize>) {
+        while out.push_checked(out.len()).is_ok() {}
+    }
+
+    let mut storage = [MaybeUninit::uninit(); 10];
+    let (left, right) = storage.split_at_mut(4);
+
+    let mut small = PushSlice::new(left);
+    let mut large = PushSlice::new(right);
+    fill(&mut small);
+    fill(&mut large);
+
+    assert_eq!(small.as_slice(), &[0, 1, 2, 3]);
+    assert_eq!(large.as_slice(), &[0, 1, 2, 3, 4, 5]);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn drops_elements() {
+    let counter = Rc::new(());
+
+    let mut storage = [const { MaybeUninit::uninit() }; 4];
+    let mut arr = PushSlice::new(&mut storage);
+    arr.push(Rc::clone(&counter));
+    arr.push(Rc::clone(&counter));
+    assert_eq!(Rc::strong_count(&counter), 3);
+
+    drop(arr.pop());
+    assert_eq!(Rc::strong_count(&counter), 2);
+
+    drop(arr);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
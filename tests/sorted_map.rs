@@ -0,0 +1,117 @@
+use pushy::SortedMap;
+
+#[test]
+fn sorted_map_keeps_keys_sorted() {
+    let mut map: SortedMap<u32, char, 4> = SortedMap::new();
+    for (key, value) in [(30, 'c'), (10, 'a'), (40, 'd'), (20, 'b')] {
+        assert_eq!(map.insert(key, value).unwrap(), None);
+    }
+    assert!(map.is_full());
+    assert!(map.insert(50, 'e').is_err());
+    // Replacing doesn't need room
+    assert_eq!(map.insert(40, 'D').unwrap(), Some('d'));
+
+    let entries: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(entries, [(10, 'a'), (20, 'b'), (30, 'c'), (40, 'D')]);
+
+    assert_eq!(map.remove(&20), Some('b'));
+    assert_eq!(map.remove(&20), None);
+    assert!(!map.contains_key(&20));
+    assert_eq!(map.get(&25), None);
+
+    *map.get_mut(&10).unwrap() = 'A';
+    for (_, value) in map.iter_mut() {
+        *value = value.to_ascii_lowercase();
+    }
+    let values: String = map.values().collect();
+    assert_eq!(values, "acd");
+    let keys: Vec<_> = map.keys().copied().collect();
+    assert_eq!(keys, [10, 30, 40]);
+}
+
+#[test]
+fn sorted_map_borrowed_lookups() {
+    let mut map: SortedMap<String, usize, 2> = SortedMap::new();
+    map.insert("beta".to_owned(), 2).unwrap();
+    map.insert("alpha".to_owned(), 1).unwrap();
+
+    assert_eq!(map.get("alpha"), Some(&1));
+    assert_eq!(map.remove("beta"), Some(2));
+    assert_eq!(map.len(), 1);
+
+    map.clear();
+    assert!(map.is_empty());
+}
+
+#[test]
+fn sorted_map_entry_api() {
+    use pushy::sorted_map::Entry;
+
+    let mut map: SortedMap<&str, u32, 2> = SortedMap::new();
+    *map.entry("b").or_insert(1).unwrap() += 1;
+    map.entry("b")
+        .and_modify(|count| *count *= 10)
+        .or_insert(0)
+        .unwrap();
+    map.entry("a")
+        .and_modify(|_| unreachable!())
+        .or_default()
+        .unwrap();
+    assert_eq!(map.get("b"), Some(&20));
+    assert_eq!(map.get("a"), Some(&0));
+
+    // Full, so vacant entries can't be filled, and their default isn't computed
+    assert!(map.entry("c").or_insert_with(|| unreachable!()).is_err());
+    // But occupied ones are still reachable
+    assert_eq!(map.entry("a").or_insert(5).unwrap(), &mut 0);
+
+    match map.entry("a") {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.key(), &"a");
+            assert_eq!(entry.insert(3), 0);
+            assert_eq!(entry.remove_entry(), ("a", 3));
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+
+    match map.entry("c") {
+        Entry::Vacant(entry) => {
+            assert_eq!(entry.key(), &"c");
+            *entry.insert(7).unwrap() += 1;
+        }
+        Entry::Occupied(_) => unreachable!(),
+    }
+
+    let entries: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(entries, [("b", 20), ("c", 8)]);
+}
+
+#[test]
+fn ordered_queries() {
+    use std::ops::Bound::{self, *};
+
+    let mut map: SortedMap<u32, char, 8> = SortedMap::new();
+    assert_eq!(map.first_key_value(), None);
+    assert_eq!(map.pop_first(), None);
+
+    for (key, value) in [(5, 'e'), (1, 'a'), (3, 'c'), (7, 'g')] {
+        map.insert(key, value).unwrap();
+    }
+
+    let values = |range: (Bound<u32>, Bound<u32>)| -> String {
+        map.range(range).map(|(_, value)| value).collect()
+    };
+    assert_eq!(values((Unbounded, Unbounded)), "aceg");
+    assert_eq!(values((Included(3), Included(5))), "ce");
+    assert_eq!(values((Excluded(3), Excluded(7))), "e");
+    assert_eq!(values((Included(2), Excluded(6))), "ce");
+    assert_eq!(values((Excluded(7), Unbounded)), "");
+    assert_eq!(values((Included(6), Included(2))), "");
+    assert_eq!(map.range(2..).next_back(), Some((&7, &'g')));
+
+    assert_eq!(map.first_key_value(), Some((&1, &'a')));
+    assert_eq!(map.last_key_value(), Some((&7, &'g')));
+    assert_eq!(map.pop_first(), Some((1, 'a')));
+    assert_eq!(map.pop_last(), Some((7, 'g')));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [3, 5]);
+}
@@ -0,0 +1,240 @@
+use std::fmt::Write;
+
+use pushy::{PushArray, PushString};
+
+#[test]
+fn string_push_and_pop() {
+    let mut string: PushString<8> = PushString::new();
+    assert!(string.is_empty());
+
+    string.push_str("añ").unwrap();
+    string.push_char('€').unwrap();
+    assert_eq!(string.len(), 6);
+    assert!(string.push_char('€').is_err());
+    string.push_char('!').unwrap();
+
+    assert_eq!(string.pop(), Some('!'));
+    assert_eq!(string.pop(), Some('€'));
+    assert_eq!(string.as_str(), "añ");
+    assert_eq!(string.as_bytes(), "añ".as_bytes());
+
+    string.make_ascii_uppercase();
+    write!(string, "{}", 1).unwrap();
+    assert_eq!(string.to_string(), "Añ1");
+    assert_eq!(format!("{string:?}"), "\"Añ1\"");
+
+    string.clear();
+    assert_eq!(string.pop(), None);
+}
+
+#[test]
+fn string_from_str() {
+    let string: PushString<5> = "hello".parse().unwrap();
+    assert_eq!(&*string, "hello");
+    assert!("hello!".parse::<PushString<5>>().is_err());
+
+    let fits: Result<PushString<5>, _> = PushString::try_from("hey");
+    assert_eq!(fits.unwrap().as_str(), "hey");
+
+    let bytes: PushArray<u8, 5> = "hey".try_into().unwrap();
+    assert_eq!(bytes.as_str(), Some("hey"));
+    assert!(PushArray::<u8, 2>::try_from("hey").is_err());
+}
+
+#[test]
+fn chars_of_byte_buffers() {
+    let mut bytes: PushArray<u8, 16> = PushArray::new();
+    assert_eq!(bytes.chars().next(), None);
+
+    bytes.push_str("€uro").unwrap();
+    assert_eq!(bytes.chars().rev().collect::<String>(), "oru€");
+    assert_eq!(bytes.char_indices().nth(1), Some((3, 'u')));
+
+    // Invalid bytes cut iteration short
+    bytes.copy_from_slice(b"\xc0!").unwrap();
+    assert_eq!(bytes.chars().count(), 4);
+    assert_eq!(bytes.valid_str(), "€uro");
+
+    // Strings are always valid, and get these from `str`
+    let string: PushString<8> = "añ".parse().unwrap();
+    assert_eq!(string.char_indices().last(), Some((1, 'ñ')));
+}
+
+#[test]
+fn string_editing() {
+    let mut line: PushString<12> = "hello".parse().unwrap();
+
+    line.insert_str(0, "¡").unwrap();
+    line.insert(line.len(), '!').unwrap();
+    assert_eq!(&*line, "¡hello!");
+
+    line.replace_range(2..7, "olá").unwrap();
+    assert_eq!(&*line, "¡olá!");
+    line.replace_range(..=1, "").unwrap();
+    assert_eq!(line.remove(2), 'á');
+    assert_eq!(&*line, "ol!");
+
+    // Doesn't fit, so nothing changes
+    assert!(line.insert_str(1, "0123456789").is_err());
+    assert!(line.replace_range(.., "0123456789abc").is_err());
+    assert_eq!(&*line, "ol!");
+
+    // Replacing can shrink a full string
+    line.replace_range(.., "0123456789ab").unwrap();
+    line.replace_range(2..12, "").unwrap();
+    assert_eq!(&*line, "01");
+}
+
+#[test]
+#[should_panic]
+fn string_insert_off_char_boundary() {
+    let mut line: PushString<8> = "ñ".parse().unwrap();
+    let _ = line.insert(1, 'x');
+}
+
+#[test]
+#[should_panic]
+fn string_remove_past_end() {
+    let mut line: PushString<8> = "ab".parse().unwrap();
+    line.remove(2);
+}
+
+#[test]
+fn string_iteration() {
+    let string: PushString<16> = "a€b".parse().unwrap();
+
+    let mut chars = Vec::new();
+    for ch in &string {
+        chars.push(ch);
+    }
+    assert_eq!(chars, ['a', '€', 'b']);
+    assert_eq!(string.bytes().last(), Some(b'b'));
+
+    let mut owned = string.into_iter();
+    assert_eq!(owned.next(), Some('a'));
+    assert_eq!(owned.next_back(), Some('b'));
+    assert_eq!(owned.as_str(), "€");
+    assert_eq!(owned.next(), Some('€'));
+    assert_eq!(owned.next(), None);
+    assert_eq!(owned.next_back(), None);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn collect_text() {
+    let string: PushString<8> = "a-b-c".split('-').collect();
+    assert_eq!(&*string, "abc");
+    let string: PushString<8> = "añ".chars().rev().collect();
+    assert_eq!(&*string, "ña");
+
+    let bytes = PushArray::<u8, 4>::try_from_chars(['a', 'ñ']).unwrap();
+    assert_eq!(bytes.as_str(), Some("añ"));
+    let bytes = PushArray::<u8, 4>::try_from_strs(["ab", "cd"]).unwrap();
+    assert_eq!(bytes, *b"abcd");
+
+    assert!(PushString::<3>::try_from_strs(["ab", "cd"]).is_err());
+    assert!(PushArray::<u8, 1>::try_from_chars(['ñ']).is_err());
+    assert!(PushArray::<u8, 2>::try_from_strs(["a", "b"]).is_ok());
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+#[should_panic]
+fn collect_text_overflow() {
+    let _: PushString<3> = "abcd".chars().collect();
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn extend_string() {
+    let mut string: PushString<8> = PushString::new();
+    string.extend(['a', 'ñ']);
+    string.extend(["b", "", "cd"]);
+    assert_eq!(&*string, "añbcd");
+
+    fn append_all<'a>(out: &mut impl Extend<&'a str>, pieces: &[&'a str]) {
+        out.extend(pieces.iter().copied());
+    }
+    append_all(&mut string, &["e"]);
+    assert_eq!(&*string, "añbcde");
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+#[should_panic]
+fn extend_string_overflow() {
+    let mut string: PushString<2> = PushString::new();
+    string.extend(["a", "bc"]);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn concatenation() {
+    let mut string: PushString<8> = PushString::new() + "ab";
+    string += "ñ";
+    string += "";
+    assert_eq!(&*string, "abñ");
+
+    let suffix = String::from("cd");
+    assert_eq!(&*(string.clone() + &suffix), "abñcd");
+
+    // Fallible alternative to `+=`
+    assert!(string.push_str("too long").is_err());
+    assert_eq!(&*string, "abñ");
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+#[should_panic]
+fn concatenation_overflow() {
+    let mut string: PushString<2> = PushString::new();
+    string += "abc";
+}
+
+#[test]
+fn lossy_writing() {
+    let mut string: PushString<5> = PushString::new();
+
+    let mut writer = string.lossy_writer();
+    write!(writer, "añ").unwrap();
+    assert!(!writer.is_truncated());
+    // Only half of the 'ñ' would fit
+    write!(writer, "bñ").unwrap();
+    assert!(writer.is_truncated());
+    write!(writer, "c").unwrap();
+
+    assert_eq!(&*string, "añb");
+    // The strict implementation still fails
+    assert!(write!(string, "cd").is_err());
+}
+
+#[test]
+fn string_ascii_case() {
+    let string: PushString<8> = "Añb".parse().unwrap();
+
+    let mut out: PushString<6> = PushString::new();
+    string.to_ascii_uppercase_into(&mut out).unwrap();
+    assert_eq!(&*out, "AñB");
+    assert!(string.to_ascii_lowercase_into(&mut out).is_err());
+
+    out.clear();
+    string.to_ascii_lowercase_into(&mut out).unwrap();
+    assert_eq!(&*out, "añb");
+}
+
+#[test]
+fn compare_with_str() {
+    let name: PushString<8> = "boot".parse().unwrap();
+
+    assert_eq!(name, "boot");
+    assert_eq!("boot", name);
+    assert_eq!(name, *"boot");
+    assert_eq!(*"boot", name);
+    assert_ne!(name, "boots");
+
+    #[cfg(feature = "alloc")]
+    {
+        assert_eq!(name, String::from("boot"));
+        assert_eq!(String::from("boot"), name);
+    }
+}
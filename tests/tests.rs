@@ -1,7 +1,19 @@
+#![allow(
+    clippy::bool_assert_comparison,
+    clippy::explicit_auto_deref,
+    clippy::into_iter_on_ref,
+    clippy::unnecessary_operation
+)]
+
+#[cfg(not(feature = "no-panic-api"))]
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::sync::Arc;
 
-use pushy::PushArray;
+#[cfg(not(feature = "no-panic-api"))]
+use pushy::{Cursor, PeekHeapMut};
+use pushy::{DoubleBuffer, PushArray, PushyIteratorExt};
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn is_fully_initialized() {
     let mut arr: PushArray<_, 2> = PushArray::new();
@@ -24,6 +36,7 @@ fn into_array_unchecked() {
     assert_eq!(array, ["A", "B"]);
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn into_array_fully_initialized() {
     let mut bytes: PushArray<_, 3> = PushArray::new();
@@ -36,6 +49,7 @@ fn into_array_fully_initialized() {
     assert_eq!(bytes.into_array(), Ok(["A", "B", "C"]));
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn into_array_fails_when_not_fully_initialized() {
     let mut bytes: PushArray<_, 3> = PushArray::new();
@@ -50,6 +64,7 @@ fn into_array_fails_when_not_fully_initialized() {
     assert_eq!(bytes.into_array(), Err(backup));
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn drop() {
     let arc = Arc::new(0);
@@ -71,6 +86,7 @@ fn drop() {
     assert_eq!(Arc::strong_count(&arc), 1);
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn clear() {
     let arc = Arc::new(0);
@@ -89,6 +105,7 @@ fn clear() {
     assert_eq!(*popped, 0);
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn pop_drop() {
     let arc = Arc::new(0);
@@ -101,6 +118,7 @@ fn pop_drop() {
     assert_eq!(Arc::strong_count(&arc), 1);
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn pop_str() {
     let mut arr: PushArray<&str, 2> = PushArray::new();
@@ -117,6 +135,7 @@ fn pop_str() {
     assert_eq!(popped, "There");
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn partial_eq() {
     let mut arr1: PushArray<u64, 2> = PushArray::new();
@@ -130,6 +149,7 @@ fn partial_eq() {
     assert_eq!(arr1, arr2);
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn into_iter() {
     let mut arr: PushArray<u64, 2> = PushArray::new();
@@ -178,6 +198,7 @@ fn push_checked() {
     assert!(arr.push_checked(60).is_err());
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn length() {
     let mut bytes: PushArray<u8, 9> = PushArray::new();
@@ -212,6 +233,7 @@ fn push_array() {
     assert_eq!(bytes.as_str(), Some("HelloHello"));
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn as_str_and_push_str() {
     let mut bytes: PushArray<u8, 11> = PushArray::new();
@@ -283,6 +305,7 @@ fn index_panics_when_out_of_bounds() {
     arr[3]; // uh-oh
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 #[should_panic]
 fn panics_when_overflows() {
@@ -291,6 +314,7 @@ fn panics_when_overflows() {
     numbers.push(3); // uh-oh!
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn initialized_i32() {
     let mut numbers: PushArray<u32, 50> = PushArray::new();
@@ -302,6 +326,7 @@ fn initialized_i32() {
     assert_eq!(numbers.as_slice(), &[2, 5, 7, 2, 3, 4]);
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn initialized_str() {
     let mut words: PushArray<&str, 50> = PushArray::new();
@@ -321,6 +346,7 @@ fn initiliazed_when_uninitialized() {
     assert_eq!(numbers.initialized(), &[])
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn collect_iterator() {
     let array = [1, 2, 3, 4];
@@ -329,6 +355,17 @@ fn collect_iterator() {
     assert_eq!(numbers.as_slice(), array.as_slice());
 }
 
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn collect_infers_the_element_type() {
+    let chars = "ab".chars().collect::<PushArray<_, 4>>();
+    assert_eq!(chars, ['a', 'b']);
+
+    let words = ["hey", "there"].into_iter().collect::<PushArray<_, 2>>();
+    assert_eq!(words, ["hey", "there"]);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 #[should_panic]
 fn collect_iterator_capacity_error() {
@@ -338,6 +375,7 @@ fn collect_iterator_capacity_error() {
     assert_eq!(numbers.as_ref(), array.as_slice());
 }
 
+#[cfg(not(feature = "no-panic-api"))]
 #[test]
 fn collect_iterator_empty_without_capacity_dont_panic() {
     let array = [];
@@ -345,3 +383,1691 @@ fn collect_iterator_empty_without_capacity_dont_panic() {
 
     assert_eq!(numbers.as_slice(), array.as_slice());
 }
+
+#[test]
+fn join() {
+    let mut line: PushArray<u8, 13> = PushArray::new();
+    line.join(["temp", "hum", "pres"], b",").unwrap();
+    assert_eq!(line.as_str(), Some("temp,hum,pres"));
+
+    let mut empty: PushArray<u8, 4> = PushArray::new();
+    empty.join::<_, &[u8]>([], b",").unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn join_is_atomic_on_overflow() {
+    let mut line: PushArray<u8, 8> = PushArray::new();
+    line.push_str("id:").unwrap();
+
+    assert!(line.join(["1", "2", "3"], b", ").is_err());
+    assert_eq!(line.as_str(), Some("id:"));
+}
+
+#[test]
+fn get_disjoint_mut() {
+    let mut arr: PushArray<u8, 5> = PushArray::new();
+    arr.push_str("abcd").unwrap();
+
+    let [a, b, d] = arr.get_disjoint_mut([0, 1, 3]).unwrap();
+    *a = b'A';
+    *b = b'B';
+    *d = b'D';
+    assert_eq!(arr.as_str(), Some("ABcD"));
+
+    assert!(arr.get_disjoint_mut([2, 0, 2]).is_none());
+    assert!(arr.get_disjoint_mut([4]).is_none());
+    assert!(arr.get_disjoint_mut([]).is_some());
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn swap_contents() {
+    let arc = Arc::new(0);
+
+    let mut front: PushArray<_, 3> = PushArray::new();
+    let mut back: PushArray<_, 3> = PushArray::new();
+    front.push(arc.clone());
+    back.push(arc.clone());
+    back.push(arc.clone());
+
+    front.swap_contents(&mut back);
+    assert_eq!(front.len(), 2);
+    assert_eq!(back.len(), 1);
+    assert_eq!(Arc::strong_count(&arc), 4);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn swap_contents_checked() {
+    let arc = Arc::new(0);
+
+    let mut small: PushArray<_, 2> = PushArray::new();
+    let mut large: PushArray<_, 5> = PushArray::new();
+    small.push(arc.clone());
+    for _ in 0..2 {
+        large.push(arc.clone());
+    }
+
+    small.swap_contents_checked(&mut large).unwrap();
+    assert_eq!(small.len(), 2);
+    assert_eq!(large.len(), 1);
+
+    large.swap_contents_checked(&mut small).unwrap();
+    assert_eq!(small.len(), 1);
+    assert_eq!(large.len(), 2);
+
+    large.push(arc.clone());
+    assert!(small.swap_contents_checked(&mut large).is_err());
+    assert_eq!(small.len(), 1);
+    assert_eq!(large.len(), 3);
+
+    core::mem::drop((small, large));
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn ensure_capacity_for() {
+    let mut arr: PushArray<u8, 3> = PushArray::new();
+    assert!(arr.ensure_capacity_for(3).is_ok());
+    assert!(arr.ensure_capacity_for(4).is_err());
+
+    arr.push_str("ab").unwrap();
+    assert!(arr.ensure_capacity_for(1).is_ok());
+    assert!(arr.ensure_capacity_for(2).is_err());
+    assert!(arr.ensure_capacity_for(usize::MAX).is_err());
+
+    arr.push(b'c');
+    assert!(arr.ensure_capacity_for(0).is_ok());
+    assert!(arr.ensure_capacity_for(1).is_err());
+}
+
+#[test]
+fn push_repeat() {
+    let arc = Arc::new(0);
+    let mut arr: PushArray<_, 4> = PushArray::new();
+
+    arr.push_repeat(arc.clone(), 3).unwrap();
+    assert_eq!(arr.len(), 3);
+    assert_eq!(Arc::strong_count(&arc), 4);
+
+    // Doesn't fit: the given value is dropped and nothing is pushed
+    assert!(arr.push_repeat(arc.clone(), 2).is_err());
+    assert_eq!(arr.len(), 3);
+    assert_eq!(Arc::strong_count(&arc), 4);
+
+    arr.push_repeat(arc.clone(), 0).unwrap();
+    assert_eq!(arr.len(), 3);
+    assert_eq!(Arc::strong_count(&arc), 4);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn push_iter() {
+    let mut arr: PushArray<&str, 4> = PushArray::new();
+    arr.push("first");
+
+    assert_eq!(arr.push_iter(["a", "b"]), 2);
+    assert_eq!(arr.as_slice(), &["first", "a", "b"]);
+
+    let mut words = ["c", "d", "e"].into_iter();
+    assert_eq!(arr.push_iter(&mut words), 1);
+    assert_eq!(words.next(), Some("d"));
+
+    assert_eq!(arr.push_iter(words), 0);
+    assert!(arr.is_fully_initialized());
+}
+
+#[test]
+fn push_chunks() {
+    let chunks: Vec<_> = (0..7u8).push_chunks::<3>().collect();
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[0], [0, 1, 2]);
+    assert_eq!(chunks[1], [3, 4, 5]);
+    assert_eq!(chunks[2], [6]);
+
+    assert_eq!((0..6).push_chunks::<3>().size_hint(), (2, Some(2)));
+    assert_eq!((0..6).push_chunks::<0>().next(), None);
+    assert_eq!(core::iter::empty::<u8>().push_chunks::<3>().next(), None);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn collect_at_end_of_chain() {
+    let words = ["a", "bb", "ccc", "dddd"];
+    let lengths = words
+        .iter()
+        .map(|w| w.len())
+        .try_collect_pushy::<4>()
+        .unwrap();
+    assert_eq!(lengths, [1, 2, 3, 4]);
+
+    let empty = core::iter::empty::<u8>().try_collect_pushy::<0>().unwrap();
+    assert!(empty.is_empty());
+
+    // Collection stops at the first element that doesn't fit
+    let mut source = 0..10;
+    assert!(source.by_ref().try_collect_pushy::<3>().is_err());
+    assert_eq!(source.next(), Some(4));
+
+    let doubled = (1..=3).map(|n| n * 2).collect_pushy::<3>();
+    assert_eq!(doubled, [2, 4, 6]);
+}
+
+#[test]
+fn fallible_collection_into_any_container() {
+    use pushy::{PushString, PushWString, SortedMap, TryFromIterator};
+
+    fn collect<C: TryFromIterator<char>>(text: &str) -> pushy::Result<C> {
+        C::try_from_iter(text.chars())
+    }
+
+    assert_eq!(
+        collect::<PushArray<char, 3>>("abc").unwrap(),
+        ['a', 'b', 'c']
+    );
+    assert_eq!(collect::<PushString<3>>("añ").unwrap(), "añ");
+    assert_eq!(
+        collect::<PushWString<2>>("añ").unwrap().as_wide(),
+        &[0x61, 0xf1]
+    );
+
+    assert!(collect::<PushArray<char, 2>>("abc").is_err());
+    assert!(collect::<PushString<2>>("añ").is_err());
+    assert!(collect::<PushWString<1>>("añ").is_err());
+
+    let joined = PushString::<8>::try_from_iter(["ab", "cd"]).unwrap();
+    assert_eq!(joined, "abcd");
+
+    let map = SortedMap::<u8, &str, 2>::try_from_iter([(2, "b"), (1, "a"), (2, "c")]).unwrap();
+    assert_eq!(map.get(&2), Some(&"c"));
+    assert!(SortedMap::<u8, (), 2>::try_from_iter([(1, ()), (2, ()), (3, ())]).is_err());
+}
+
+#[test]
+fn pop_if() {
+    let mut stack: PushArray<u32, 3> = PushArray::new();
+    assert_eq!(stack.pop_if(|_| true), None);
+
+    stack.push_array([7, 8, 9]).unwrap();
+    assert_eq!(stack.pop_if(|top| *top > 10), None);
+    assert_eq!(stack.len(), 3);
+
+    // The predicate may modify the element even when it isn't popped
+    assert_eq!(
+        stack.pop_if(|top| {
+            *top += 1;
+            false
+        }),
+        None
+    );
+    assert_eq!(stack.pop_if(|top| *top == 10), Some(10));
+    assert_eq!(stack.as_slice(), &[7, 8]);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn split_tail() {
+    let arc = Arc::new(0);
+    let mut arr: PushArray<_, 4> = PushArray::new();
+    for _ in 0..4 {
+        arr.push(arc.clone());
+    }
+
+    let tail = arr.split_tail(3).unwrap();
+    assert_eq!(arr.len(), 1);
+    assert_eq!(tail.len(), 3);
+    assert_eq!(Arc::strong_count(&arc), 5);
+
+    assert!(arr.split_tail(2).is_none());
+    assert_eq!(arr.split_tail(0).unwrap().len(), 0);
+
+    core::mem::drop(tail);
+    assert_eq!(Arc::strong_count(&arc), 2);
+}
+
+#[test]
+fn split_tail_keeps_order() {
+    let mut words: PushArray<&str, 4> = PushArray::new();
+    words.push_array(["a", "b", "c", "d"]).unwrap();
+
+    let tail = words.split_tail(4).unwrap();
+    assert!(words.is_empty());
+    assert_eq!(tail.as_slice(), &["a", "b", "c", "d"]);
+}
+
+#[test]
+fn as_array() {
+    let mut arr: PushArray<u8, 6> = PushArray::new();
+    arr.push_str("abcd").unwrap();
+
+    assert_eq!(arr.as_array::<0>(), Some(&[]));
+    assert_eq!(arr.as_array(), Some(b"abc"));
+    assert_eq!(arr.as_array(), Some(b"abcd"));
+    assert_eq!(arr.as_array::<5>(), None);
+
+    arr.as_mut_array::<2>().unwrap().reverse();
+    assert_eq!(arr.as_str(), Some("bacd"));
+    assert!(arr.as_mut_array::<5>().is_none());
+}
+
+#[test]
+fn try_from_push_array_for_array() {
+    let mut arr: PushArray<u8, 32> = PushArray::new();
+    arr.push_repeat(7, 16).unwrap();
+
+    let view: &[u8; 16] = (&arr).try_into().unwrap();
+    assert_eq!(view, &[7; 16]);
+
+    let owned: [u8; 8] = (&arr).try_into().unwrap();
+    assert_eq!(owned, [7; 8]);
+
+    assert!(<[u8; 32]>::try_from(&arr).is_err());
+    assert!(<&[u8; 17]>::try_from(&arr).is_err());
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn raw_parts_round_trip() {
+    let arc = Arc::new(0);
+
+    let mut arr: PushArray<_, 4> = PushArray::new();
+    arr.push(arc.clone());
+    arr.push(arc.clone());
+
+    let (buf, len) = arr.into_raw_parts();
+    assert_eq!(len, 2);
+    // Nothing was dropped when decomposing the array
+    assert_eq!(Arc::strong_count(&arc), 3);
+
+    let arr = unsafe { PushArray::from_raw_parts(buf, len) };
+    assert_eq!(arr.len(), 2);
+
+    core::mem::drop(arr);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[test]
+#[cfg(not(feature = "stats"))]
+fn small_len_type() {
+    use core::mem::size_of;
+
+    assert_eq!(size_of::<PushArray<u8, 16, u8>>(), 17);
+    assert_eq!(size_of::<PushArray<u8, 300, u16>>(), 302);
+    assert!(size_of::<PushArray<u8, 16, u8>>() < size_of::<PushArray<u8, 16>>());
+
+    let mut arr: PushArray<u8, 255, u8> = PushArray::new();
+    arr.push_repeat(b'x', 250).unwrap();
+    arr.push_str("abcde").unwrap();
+    assert_eq!(arr.len(), 255);
+    assert!(arr.is_fully_initialized());
+    assert!(arr.push_checked(b'!').is_err());
+
+    assert_eq!(arr.pop(), Some(b'e'));
+    assert_eq!(arr.len(), 254);
+    assert_eq!(&arr[250..], b"abcd");
+}
+
+#[test]
+fn spare_capacity_and_set_len() {
+    let mut arr: PushArray<u8, 5> = PushArray::new();
+    arr.push_str("ab").unwrap();
+    assert_eq!(arr.as_ptr(), arr.as_slice().as_ptr());
+
+    for (slot, byte) in arr.spare_capacity_mut().iter_mut().zip(*b"cde") {
+        slot.write(byte);
+    }
+    unsafe { arr.set_len(5) };
+
+    assert_eq!(arr.as_str(), Some("abcde"));
+    assert!(arr.spare_capacity_mut().is_empty());
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn into_array_does_not_double_drop() {
+    let arc = Arc::new(0);
+
+    let mut arr: PushArray<_, 2> = PushArray::new();
+    arr.push(arc.clone());
+    arr.push(arc.clone());
+
+    let array = arr.into_array().unwrap();
+    assert_eq!(Arc::strong_count(&arc), 3);
+
+    core::mem::drop(array);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[test]
+fn pointers_into_the_buffer() {
+    let mut arr: PushArray<u32, 4> = PushArray::new();
+    arr.push_array([1, 2, 3]).unwrap();
+
+    // Accessing past the first element must stay within the provenance of the pointer
+    let ptr = arr.as_mut_ptr();
+    unsafe { *ptr.add(2) += 10 };
+
+    // Uninitialized slots are written through the spare capacity instead
+    let (initialized, spare) = arr.split_at_spare_mut();
+    assert_eq!(initialized, &[1, 2, 13]);
+    spare[0].write(4);
+    unsafe { arr.set_len(4) };
+    assert_eq!(arr.as_slice(), &[1, 2, 13, 4]);
+
+    let ptr = arr.as_ptr();
+    let sum: u32 = (0..arr.len()).map(|i| unsafe { ptr.add(i).read() }).sum();
+    assert_eq!(sum, 20);
+}
+
+#[test]
+fn overflow_policies() {
+    use pushy::policy::{OverwriteOldest, Reject, Saturate};
+
+    let mut arr: PushArray<u8, 2> = PushArray::new();
+    assert!(arr.push_with::<Reject>(1).is_ok());
+    assert!(arr.push_with::<Saturate>(2));
+    assert!(arr.push_with::<Reject>(3).is_err());
+    assert!(!arr.push_with::<Saturate>(3));
+    assert_eq!(arr.as_slice(), &[1, 2]);
+
+    assert_eq!(arr.push_with::<OverwriteOldest>(3), Some(1));
+    assert_eq!(arr.push_with::<OverwriteOldest>(4), Some(2));
+    assert_eq!(arr.as_slice(), &[3, 4]);
+
+    let mut empty: PushArray<u8, 0> = PushArray::new();
+    assert_eq!(empty.push_with::<OverwriteOldest>(1), Some(1));
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+#[should_panic]
+fn panic_policy_panics_on_overflow() {
+    use pushy::policy::Panic;
+
+    let mut arr: PushArray<u8, 1> = PushArray::new();
+    arr.push_with::<Panic>(1);
+    arr.push_with::<Panic>(2);
+}
+
+#[test]
+fn overwrite_oldest_drops_nothing_twice() {
+    use pushy::policy::OverwriteOldest;
+
+    let arc = Arc::new(0);
+    let mut arr: PushArray<_, 3> = PushArray::new();
+    for _ in 0..10 {
+        let evicted = arr.push_with::<OverwriteOldest>(arc.clone());
+        core::mem::drop(evicted);
+    }
+
+    assert_eq!(Arc::strong_count(&arc), 4);
+    core::mem::drop(arr);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "no capacity left")]
+fn push_unchecked_asserts_capacity_in_debug() {
+    let mut arr: PushArray<u8, 1> = PushArray::new();
+    unsafe {
+        arr.push_unchecked(1);
+        arr.push_unchecked(2);
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "greater than CAP")]
+fn set_len_asserts_capacity_in_debug() {
+    let mut arr: PushArray<u8, 4> = PushArray::new();
+    unsafe { arr.set_len(5) };
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+#[should_panic(expected = "overflow in PushArray!")]
+fn collect_fails_fast_on_size_hint() {
+    struct Endless;
+
+    impl Iterator for Endless {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            unreachable!("the size hint alone shows this doesn't fit")
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (usize::MAX, None)
+        }
+    }
+
+    let _: PushArray<u8, 16> = Endless.collect();
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn collect_large_range() {
+    let numbers: PushArray<u32, 1024> = (0..1000).collect();
+
+    assert_eq!(numbers.len(), 1000);
+    assert_eq!(numbers.iter().sum::<u32>(), 499_500);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn collect_drops_collected_elements_when_iterator_panics() {
+    let arc = Arc::new(0);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let _: PushArray<_, 8> = (0..8)
+            .map(|i| {
+                if i == 5 {
+                    panic!("iterator panicked");
+                }
+                arc.clone()
+            })
+            .collect();
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn collect_drops_collected_elements_on_overflow() {
+    let arc = Arc::new(0);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        // Filtering hides the length of the iterator, so it can't fail fast
+        let _: PushArray<_, 3> = (0..5).map(|_| arc.clone()).filter(|_| true).collect();
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn clone_drops_cloned_elements_when_clone_panics() {
+    struct PanicsOnClone(Arc<i32>);
+
+    impl Clone for PanicsOnClone {
+        fn clone(&self) -> Self {
+            if *self.0 == 1 {
+                panic!("clone panicked");
+            }
+            Self(self.0.clone())
+        }
+    }
+
+    let ok = Arc::new(0);
+    let bad = Arc::new(1);
+
+    let mut arr: PushArray<_, 4> = PushArray::new();
+    arr.push(PanicsOnClone(ok.clone()));
+    arr.push(PanicsOnClone(ok.clone()));
+    arr.push(PanicsOnClone(bad.clone()));
+
+    let result = catch_unwind(AssertUnwindSafe(|| arr.clone()));
+
+    assert!(result.is_err());
+    assert_eq!(Arc::strong_count(&ok), 3);
+
+    core::mem::drop(arr);
+    assert_eq!(Arc::strong_count(&ok), 1);
+    assert_eq!(Arc::strong_count(&bad), 1);
+}
+
+#[test]
+fn ct_eq() {
+    let mut mac: PushArray<u8, 8> = PushArray::new();
+    mac.push_str("abcdef").unwrap();
+
+    let mut same: PushArray<u8, 32> = PushArray::new();
+    same.push_str("abcdef").unwrap();
+
+    assert!(mac.ct_eq(&same));
+    assert!(mac.ct_eq(b"abcdef"));
+    assert!(!mac.ct_eq(b"abcdeF"));
+    assert!(!mac.ct_eq(b"Abcdef"));
+    assert!(!mac.ct_eq(b"abcdefg"));
+    assert!(!mac.ct_eq(b""));
+
+    let empty: PushArray<u8, 0> = PushArray::new();
+    assert!(empty.ct_eq(b""));
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn external_fill() {
+    let mut rx: PushArray<u32, 4> = PushArray::new();
+    rx.push(1);
+
+    {
+        let mut fill = rx.external_fill();
+        assert_eq!(fill.capacity(), 3);
+
+        let ptr = fill.as_mut_ptr();
+        unsafe {
+            ptr.write(2);
+            ptr.add(1).write(3);
+            fill.commit(2);
+        }
+    }
+    assert_eq!(rx.as_slice(), &[1, 2, 3]);
+
+    // Letting go of the guard commits nothing
+    unsafe { rx.external_fill().as_mut_ptr().write(4) };
+    assert_eq!(rx.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+fn push_display_and_write() {
+    use std::fmt::Write;
+
+    let mut bytes: PushArray<u8, 8> = PushArray::new();
+    let (number, letter) = (12, 'x');
+    write!(bytes, "{number}-{letter}").unwrap();
+    assert_eq!(bytes.as_str(), Some("12-x"));
+
+    bytes.push_display(&-3).unwrap();
+    assert_eq!(bytes.as_str(), Some("12-x-3"));
+
+    // Fails as a whole, even if the first pieces would fit
+    assert!(bytes
+        .push_display(&format_args!("{number}{number}"))
+        .is_err());
+    assert_eq!(bytes.as_str(), Some("12-x-3"));
+    assert!(bytes.write_str("abc").is_err());
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn push_integers() {
+    let mut bytes: PushArray<u8, 80> = PushArray::new();
+    bytes.push_u32_dec(0).unwrap();
+    bytes.push(b' ');
+    bytes.push_u64_dec(u64::MAX).unwrap();
+    bytes.push(b' ');
+    bytes.push_i32_dec(-42).unwrap();
+    bytes.push(b' ');
+    bytes.push_i64_dec(i64::MIN).unwrap();
+    bytes.push(b' ');
+    bytes.push_u32_hex(0xbeef).unwrap();
+    bytes.push(b' ');
+    bytes.push_u64_hex(u64::MAX).unwrap();
+    assert_eq!(
+        bytes.as_str(),
+        Some("0 18446744073709551615 -42 -9223372036854775808 beef ffffffffffffffff")
+    );
+
+    let mut small: PushArray<u8, 3> = PushArray::new();
+    assert!(small.push_i32_dec(-100).is_err());
+    assert!(small.is_empty());
+    small.push_i32_dec(-99).unwrap();
+    assert_eq!(small.as_str(), Some("-99"));
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn push_floats() {
+    let mut bytes: PushArray<u8, 32> = PushArray::new();
+    for value in [1.0, -2.5, 1e-7, f32::NAN, f32::NEG_INFINITY] {
+        bytes.push_f32(value).unwrap();
+        bytes.push(b' ');
+    }
+    assert_eq!(bytes.as_str(), Some("1 -2.5 1e-7 NaN -inf "));
+
+    // Exponents are only used outside of 1e-5..1e16
+    let mut bytes: PushArray<u8, 96> = PushArray::new();
+    for value in [
+        0.00001,
+        0.00000123,
+        1e15,
+        1e16,
+        1.5e300,
+        -0.0,
+        f64::MIN_POSITIVE,
+    ] {
+        bytes.push_f64(value).unwrap();
+        bytes.push(b' ');
+    }
+    assert_eq!(
+        bytes.as_str(),
+        Some("0.00001 1.23e-6 1000000000000000 1e16 1.5e300 -0 2.2250738585072014e-308 ")
+    );
+
+    // Round trips
+    let mut bytes: PushArray<u8, 32> = PushArray::new();
+    bytes.push_f64(std::f64::consts::PI).unwrap();
+    assert_eq!(bytes.as_str().unwrap().parse(), Ok(std::f64::consts::PI));
+
+    assert!(bytes.push_f64(-1.2345678901234567e-300).is_err());
+    assert_eq!(bytes.as_str(), Some("3.141592653589793"));
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn ordering_against_slices() {
+    let mut arr: PushArray<u8, 4> = PushArray::new();
+    arr.copy_from_slice(&[1, 2, 3]).unwrap();
+
+    assert!(arr < [1, 2, 4]);
+    assert!(arr > [1, 2]);
+    let slice: &[u8] = &[1, 2, 3];
+    assert!(arr <= slice);
+    #[cfg(feature = "alloc")]
+    assert!(arr >= vec![0, 9]);
+
+    let other: PushArray<u8, 8> = [1, 3].into_iter().collect();
+    assert!(arr < other);
+    assert_eq!(arr.cmp(&arr.clone()), std::cmp::Ordering::Equal);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn cross_type_equality() {
+    let words: PushArray<String, 4> = ["hey".to_owned(), "there".to_owned()].into_iter().collect();
+
+    assert_eq!(words, ["hey", "there"]);
+    assert_eq!(words, &["hey", "there"][..]);
+    assert_ne!(words, ["hey"]);
+
+    // And the other way around
+    assert_eq!(["hey", "there"], words);
+    assert_eq!(&["hey", "there"][..], words);
+
+    let strs: PushArray<&str, 2, u8> = ["hey", "there"].into_iter().collect();
+    assert_eq!(words, strs);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+#[cfg(feature = "alloc")]
+fn equality_against_owned_slices() {
+    use std::borrow::Cow;
+
+    let arr: PushArray<u8, 4> = [1, 2, 3].into_iter().collect();
+
+    assert_eq!(arr, vec![1, 2, 3]);
+    assert_eq!(vec![1, 2, 3], arr);
+    assert_eq!(arr, vec![1, 2, 3].into_boxed_slice());
+    assert_eq!(Cow::Borrowed(&[1u8, 2, 3][..]), arr);
+    assert_ne!(arr, Cow::<[u8]>::Owned(vec![1, 2]));
+
+    let words: PushArray<String, 2> = ["hey".to_owned()].into_iter().collect();
+    assert_eq!(words, vec!["hey"]);
+}
+
+#[test]
+fn hash_matches_slices() {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashMap},
+        hash::{Hash, Hasher},
+    };
+
+    fn hash_of(value: &(impl Hash + ?Sized)) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let key: PushArray<u8, 8> = "key".try_into().unwrap();
+    assert_eq!(hash_of(&key), hash_of(&b"key"[..]));
+
+    let mut map = HashMap::new();
+    map.insert(key, 1);
+    assert_eq!(map.get(&b"key"[..]), Some(&1));
+    assert_eq!(map.get(&b"ke"[..]), None);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn array_windows_and_chunks() {
+    let arr: PushArray<u8, 8> = (1..=5).collect();
+
+    let windows: Vec<_> = arr.array_windows::<3>().collect();
+    assert_eq!(windows, [&[1, 2, 3], &[2, 3, 4], &[3, 4, 5]]);
+    assert_eq!(arr.array_windows::<3>().next_back(), Some(&[3, 4, 5]));
+    assert_eq!(arr.array_windows::<5>().len(), 1);
+    assert_eq!(arr.array_windows::<6>().next(), None);
+
+    let mut chunks = arr.array_chunks::<2>();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.next_back(), Some(&[3, 4]));
+    assert_eq!(chunks.next(), Some(&[1, 2]));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(chunks.remainder(), [5]);
+
+    let empty: PushArray<u8, 8> = PushArray::new();
+    assert_eq!(empty.array_windows::<1>().len(), 0);
+    assert_eq!(empty.array_chunks::<1>().next(), None);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn overlapping_frames() {
+    use pushy::Framer;
+
+    let samples: PushArray<u32, 16> = (0..10).collect();
+
+    let frames: Vec<_> = samples.frames(4, 3).collect();
+    assert_eq!(frames, [&[0, 1, 2, 3][..], &[3, 4, 5, 6], &[6, 7, 8, 9]]);
+    assert_eq!(samples.frames(4, 3).len(), 3);
+    assert_eq!(
+        samples.frames(3, 5).collect::<Vec<_>>(),
+        [&[0, 1, 2][..], &[5, 6, 7]]
+    );
+    assert_eq!(samples.frames(10, 1).len(), 1);
+    assert_eq!(samples.frames(11, 1).next(), None);
+
+    // The framer emits the same frames as samples arrive
+    let mut framer: Framer<u32, 4, 3> = Framer::new();
+    let pushed: Vec<_> = samples
+        .iter()
+        .filter_map(|&s| framer.push(s).copied())
+        .collect();
+    assert_eq!(pushed, [[0, 1, 2, 3], [3, 4, 5, 6], [6, 7, 8, 9]]);
+    assert_eq!(framer.pending(), [6, 7, 8, 9]);
+    assert_eq!(framer.push(10), None);
+    assert_eq!(framer.pending(), [9, 10]);
+
+    framer.clear();
+    assert!(framer.pending().is_empty());
+
+    // Non-overlapping frames, with samples that need dropping
+    let arc = Arc::new(());
+    let mut framer: Framer<Arc<()>, 2, 2> = Framer::default();
+    for _ in 0..5 {
+        framer.push(arc.clone());
+    }
+    assert_eq!(Arc::strong_count(&arc), 2);
+    std::mem::drop(framer);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[test]
+#[should_panic(expected = "the hop size must be non-zero")]
+fn frames_with_zero_hop() {
+    let samples: PushArray<u8, 4> = PushArray::new();
+    samples.frames(2, 0);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn chunk_by_runs() {
+    let arr: PushArray<u32, 8> = [1, 2, 3, 10, 11, 20].into_iter().collect();
+
+    let ascending: Vec<_> = arr.chunk_by(|a, b| a + 1 == *b).collect();
+    assert_eq!(ascending, [&[1, 2, 3][..], &[10, 11], &[20]]);
+
+    let mut runs = arr.chunk_by(|a, b| a + 1 == *b);
+    assert_eq!(runs.next_back(), Some(&[20][..]));
+    assert_eq!(runs.next(), Some(&[1, 2, 3][..]));
+    assert_eq!(runs.next_back(), Some(&[10, 11][..]));
+    assert_eq!(runs.next(), None);
+
+    let empty: PushArray<u32, 8> = PushArray::new();
+    assert_eq!(empty.chunk_by(|_, _| true).count(), 0);
+}
+
+#[test]
+fn interleaving_round_trip() {
+    let mut interleaved: PushArray<u8, 6> = PushArray::new();
+    interleaved
+        .push_interleaved(&[&[1, 2], &[10, 20], &[100, 200]])
+        .unwrap();
+    assert_eq!(interleaved, [1, 10, 100, 2, 20, 200]);
+
+    // Mismatched channels or not enough room leave the array untouched
+    assert!(interleaved.push_interleaved(&[&[1][..], &[]]).is_err());
+    assert!(interleaved.push_interleaved(&[&[1]]).is_err());
+    assert_eq!(interleaved.len(), 6);
+
+    let mut planar: [PushArray<u8, 2>; 3] = Default::default();
+    interleaved.deinterleave_into(&mut planar).unwrap();
+    assert_eq!(planar, [[1, 2], [10, 20], [100, 200]]);
+    // No more room in the channels
+    assert!(interleaved.deinterleave_into(&mut planar).is_err());
+
+    let mut stereo: [PushArray<u8, 8>; 2] = Default::default();
+    assert!(interleaved.deinterleave_into(&mut stereo).is_ok());
+    interleaved.pop();
+    assert!(interleaved.deinterleave_into(&mut stereo).is_err());
+    assert_eq!(stereo[0], [1, 100, 20]);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn unzip_moves_elements() {
+    let arc = Arc::new(());
+    let pairs: PushArray<(Arc<()>, &str), 4> = [(arc.clone(), "a"), (arc.clone(), "b")]
+        .into_iter()
+        .collect();
+
+    let (arcs, names) = pairs.unzip();
+    assert_eq!(arcs.len(), 2);
+    assert_eq!(names, ["a", "b"]);
+    assert_eq!(Arc::strong_count(&arc), 3);
+
+    std::mem::drop(arcs);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn heap_operations() {
+    let mut heap: PushArray<u32, 32> = [9, 2, 7, 7, 0, 13, 4, 1, 8].into_iter().collect();
+    heap.make_heap();
+    heap.push_heap(5).unwrap();
+    heap.push_heap(100).unwrap();
+
+    let mut sorted = Vec::new();
+    while let Some(greatest) = heap.pop_heap() {
+        sorted.push(greatest);
+    }
+    assert_eq!(sorted, [100, 13, 9, 8, 7, 7, 5, 4, 2, 1, 0]);
+    assert!(heap.is_empty());
+
+    let mut empty: PushArray<u32, 0> = PushArray::new();
+    empty.make_heap();
+    assert!(empty.push_heap(1).is_err());
+    assert_eq!(empty.pop_heap(), None);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn heap_peek_and_retain() {
+    let mut heap: PushArray<u32, 16> = [9, 2, 7, 13, 4, 1, 8].into_iter().collect();
+    heap.make_heap();
+
+    *heap.peek_heap_mut().unwrap() = 3;
+    assert_eq!(heap.peek_heap_mut().as_deref(), Some(&9));
+
+    let top = heap.peek_heap_mut().unwrap();
+    assert_eq!(PeekHeapMut::pop(top), 9);
+
+    heap.retain_heap(|&x| x != 7 && x != 1);
+    let mut sorted = Vec::new();
+    while let Some(greatest) = heap.pop_heap() {
+        sorted.push(greatest);
+    }
+    assert_eq!(sorted, [8, 4, 3, 2]);
+    assert!(heap.peek_heap_mut().is_none());
+
+    // Removed elements are dropped
+    let arc = Arc::new(());
+    let mut shared: PushArray<(u8, Arc<()>), 4> = PushArray::new();
+    for key in 0..4 {
+        shared.push_heap((key, arc.clone())).unwrap();
+    }
+    shared.retain_heap(|(key, _)| *key < 2);
+    assert_eq!(Arc::strong_count(&arc), 3);
+    assert_eq!(shared.pop_heap().map(|(key, _)| key), Some(1));
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn partition_moves_elements() {
+    let arc = Arc::new(());
+    let arr: PushArray<(usize, Arc<()>), 8> = (0..5).map(|idx| (idx, arc.clone())).collect();
+
+    let (small, large) = arr.partition(|(idx, _)| *idx < 2);
+    assert_eq!(
+        small.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+        [0, 1]
+    );
+    assert_eq!(
+        large.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+        [2, 3, 4]
+    );
+    assert_eq!(Arc::strong_count(&arc), 6);
+
+    std::mem::drop((small, large));
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn partition_drops_every_element_when_predicate_panics() {
+    let arc = Arc::new(());
+    let arr: PushArray<(usize, Arc<()>), 8> = (0..5).map(|idx| (idx, arc.clone())).collect();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        arr.partition(|(idx, _)| {
+            if *idx == 2 {
+                panic!("predicate panicked");
+            }
+            idx % 2 == 0
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn merge_sorted_runs() {
+    // Ordered by its key only, so that equal keys can be told apart
+    #[derive(Debug)]
+    struct Keyed(u8, char);
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let a: PushArray<Keyed, 4> = [Keyed(1, 'a'), Keyed(3, 'b'), Keyed(3, 'c')]
+        .into_iter()
+        .collect();
+    // The payloads of `b` would come first if they were compared
+    let b: PushArray<Keyed, 4, u8> = [Keyed(0, 'x'), Keyed(3, 'A')].into_iter().collect();
+
+    // Too small, so the inputs are given back
+    let (a, b) = PushArray::<Keyed, 4>::merge_sorted(a, b).unwrap_err();
+
+    let merged: PushArray<Keyed, 5> = PushArray::merge_sorted(a, b).unwrap();
+    let keys: Vec<_> = merged.iter().map(|keyed| keyed.0).collect();
+    assert_eq!(keys, [0, 1, 3, 3, 3]);
+    // Equal keys from `a` come first, in their original order
+    let payloads: String = merged.iter().map(|keyed| keyed.1).collect();
+    assert_eq!(payloads, "xabcA");
+
+    let arc = Arc::new(());
+    let a: PushArray<Arc<()>, 2> = [arc.clone()].into_iter().collect();
+    let b: PushArray<Arc<()>, 2> = [arc.clone(), arc.clone()].into_iter().collect();
+    let merged: PushArray<Arc<()>, 3> = PushArray::merge_sorted(a, b).unwrap();
+    assert_eq!(Arc::strong_count(&arc), 4);
+    std::mem::drop(merged);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn merge_sorted_drops_every_element_when_comparison_panics() {
+    // Panics when comparing against the key 3
+    #[derive(PartialEq, Eq)]
+    struct Fragile(u8, Arc<()>);
+
+    impl PartialOrd for Fragile {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Fragile {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            if self.0 == 3 || other.0 == 3 {
+                panic!("comparison panicked");
+            }
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let arc = Arc::new(());
+    let a: PushArray<Fragile, 4> = [1, 3, 5]
+        .map(|key| Fragile(key, arc.clone()))
+        .into_iter()
+        .collect();
+    let b: PushArray<Fragile, 4> = [2, 4, 6]
+        .map(|key| Fragile(key, arc.clone()))
+        .into_iter()
+        .collect();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let _ = PushArray::<Fragile, 8>::merge_sorted(a, b);
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn cobs_round_trips() {
+    let cases: [&[u8]; 7] = [
+        &[],
+        &[0],
+        &[0, 0],
+        &[1, 2, 3],
+        &[0, 1, 0, 2, 0],
+        &[7; 254],
+        &[9; 600],
+    ];
+
+    for data in cases {
+        let mut encoded: PushArray<u8, 700> = PushArray::new();
+        encoded.push_cobs_encoded(data).unwrap();
+        assert!(!encoded.contains(&0));
+        assert!(encoded.len() <= data.len() + data.len() / 254 + 1);
+
+        encoded.push(0);
+        let mut decoded: PushArray<u8, 600> = PushArray::new();
+        assert_eq!(decoded.push_cobs_decoded(&encoded).unwrap(), encoded.len());
+        assert_eq!(decoded, data);
+    }
+}
+
+#[test]
+fn cobs_full_block_at_the_end() {
+    let data: Vec<u8> = (1..=254).collect();
+
+    let mut encoded: PushArray<u8, 256> = PushArray::new();
+    encoded.push_cobs_encoded(&data).unwrap();
+
+    // A single full block, not followed by an empty one
+    assert_eq!(encoded.len(), 255);
+    assert_eq!(encoded[0], 0xFF);
+    assert_eq!(encoded[1..], data[..]);
+
+    let mut decoded: PushArray<u8, 254> = PushArray::new();
+    assert_eq!(decoded.push_cobs_decoded(&encoded).unwrap(), 255);
+    assert_eq!(decoded[..], data[..]);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn cobs_errors_leave_buffer_untouched() {
+    let mut small: PushArray<u8, 4> = PushArray::new();
+    small.push(b'!');
+    assert!(small.push_cobs_encoded(&[1, 2, 3]).is_err());
+    assert!(small.push_cobs_decoded(&[0x05, 1, 2, 3, 4]).is_err());
+    assert_eq!(small, [b'!']);
+
+    // Truncated frames
+    let mut data: PushArray<u8, 16> = PushArray::new();
+    assert!(data.push_cobs_decoded(&[0x03, 0x11]).is_err());
+    assert!(data.push_cobs_decoded(&[0x03, 0x11, 0x00, 0x22]).is_err());
+    assert!(data.is_empty());
+}
+
+#[test]
+fn tokenize_into_push_array() {
+    let args: PushArray<&str, 3> = PushArray::from_split_whitespace("\tgpio  set 4\n").unwrap();
+    assert_eq!(args, ["gpio", "set", "4"]);
+    assert!(PushArray::<&str, 2>::from_split_whitespace("gpio set 4").is_err());
+
+    let empty: PushArray<&str, 0> = PushArray::from_split_whitespace("   ").unwrap();
+    assert!(empty.is_empty());
+
+    let fields: PushArray<&str, 3> = PushArray::from_split("a;;b", ';').unwrap();
+    assert_eq!(fields, ["a", "", "b"]);
+    let single: PushArray<&str, 1> = PushArray::from_split("", ';').unwrap();
+    assert_eq!(single, [""]);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn cursor_consumes_and_drops() {
+    let arc = Arc::new(());
+    let arr: PushArray<Arc<()>, 8> = (0..6).map(|_| arc.clone()).collect();
+    let mut cursor = Cursor::new(arr);
+    assert_eq!(Arc::strong_count(&arc), 7);
+
+    assert_eq!(cursor.len(), 6);
+    let first = cursor.next().unwrap();
+    cursor.advance(2).unwrap();
+    assert_eq!(Arc::strong_count(&arc), 5);
+    assert!(cursor.advance(4).is_err());
+    assert_eq!(cursor.remaining_slice().len(), 3);
+
+    let last = cursor.next_back().unwrap();
+    assert_eq!(cursor.len(), 2);
+    std::mem::drop(cursor);
+    assert_eq!(Arc::strong_count(&arc), 3);
+
+    std::mem::drop((first, last));
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn cursor_peeks() {
+    let arr: PushArray<u8, 4> = [1, 2, 3].into_iter().collect();
+    let mut cursor = Cursor::new(arr);
+
+    assert_eq!(cursor.peek(), Some(&1));
+    cursor.remaining_slice_mut()[0] = 10;
+    assert_eq!(cursor.next(), Some(10));
+    assert_eq!(cursor.by_ref().collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(cursor.peek(), None);
+    assert_eq!(cursor.next(), None);
+    assert!(cursor.advance(0).is_ok());
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn double_buffer_flips() {
+    let mut buffers: DoubleBuffer<u32, 4> = DoubleBuffer::new();
+    buffers.back_mut().push(1);
+    assert!(buffers.front().is_empty());
+
+    buffers.swap();
+    assert_eq!(*buffers.front(), [1]);
+    assert!(buffers.back().is_empty());
+
+    let (front, back) = buffers.split_mut();
+    back.copy_from_slice(front).unwrap();
+    back.push(2);
+    front.clear();
+    buffers.swap();
+    assert_eq!(*buffers.front(), [1, 2]);
+    buffers.front_mut().pop();
+    assert_eq!(*buffers.front(), [1]);
+}
+
+#[test]
+fn double_buffer_default() {
+    // Neither the elements nor the length type need to implement `Default`
+    struct NoDefault;
+
+    let buffers: DoubleBuffer<NoDefault, 4, u8> = DoubleBuffer::default();
+    assert!(buffers.front().is_empty());
+    assert!(buffers.back().is_empty());
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+#[cfg(feature = "stats")]
+fn high_water_mark_tracking() {
+    let mut arr: PushArray<u8, 16, u8> = PushArray::new();
+    assert_eq!(arr.high_water_mark(), 0);
+
+    arr.copy_from_slice(b"hello").unwrap();
+    assert_eq!(arr.high_water_mark(), 5);
+    arr.clear();
+    arr.push(1);
+    assert_eq!(arr.high_water_mark(), 5);
+
+    // Elements written through a length guard count too
+    let filled: PushArray<u8, 16> = (0..10).collect();
+    let mut other = filled.clone();
+    other.split_tail(8).unwrap();
+    assert_eq!(other.high_water_mark(), 10);
+
+    other.reset_high_water_mark();
+    assert_eq!(other.high_water_mark(), 2);
+}
+
+#[test]
+fn uninit_round_trip() {
+    use core::mem::MaybeUninit;
+
+    let mut slots: PushArray<MaybeUninit<String>, 4> = PushArray::uninit();
+    assert_eq!(slots.len(), 4);
+
+    // Only initialize a prefix, out of order
+    slots[1].write("world".into());
+    slots[0].write("hello".into());
+    let (buf, _) = slots.into_uninit();
+
+    // Safety: the first two slots were just initialized
+    let slots: PushArray<MaybeUninit<String>, 4> = unsafe { PushArray::from_uninit(buf, 2) };
+    // Safety: same as above
+    let words = unsafe { slots.assume_init() };
+    assert_eq!(words, ["hello", "world"]);
+
+    let (buf, len) = words.into_uninit();
+    // Safety: `buf` and `len` come straight from `into_uninit`
+    let words: PushArray<String, 4> = unsafe { PushArray::from_uninit(buf, len) };
+    assert_eq!(words.len(), 2);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn scattered_writes() {
+    let mut arr: PushArray<String, 4> = PushArray::new();
+    arr.push("a".into());
+
+    // The initialized prefix and anything past `CAP` can't be written to
+    assert!(arr.write_at(0, "x".into()).is_err());
+    assert!(arr.write_at(4, "x".into()).is_err());
+
+    arr.write_at(3, "d".into()).unwrap();
+    arr.write_at(1, "b".into()).unwrap();
+    arr.write_at(2, "c".into()).unwrap();
+
+    // Safety: slots 1 to 3 were just written
+    unsafe { arr.commit_up_to(4) };
+    assert_eq!(arr, ["a", "b", "c", "d"]);
+}
+
+#[test]
+fn split_at_spare() {
+    let mut arr: PushArray<u32, 5> = PushArray::new();
+    arr.copy_from_slice(&[1, 2]).unwrap();
+
+    let (initialized, spare) = arr.split_at_spare_mut();
+    assert_eq!(initialized, &[1, 2]);
+    assert_eq!(spare.len(), 3);
+
+    initialized[0] = 10;
+    spare[0].write(initialized[0] + initialized[1]);
+
+    // Safety: the first spare slot was just written
+    unsafe { arr.set_len(3) };
+    assert_eq!(arr, [10, 2, 12]);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn fill_up_to_capacity() {
+    let mut full: PushArray<String, 3> = PushArray::new();
+    full.fill_to_capacity("x".into());
+    assert_eq!(full, ["x", "x", "x"]);
+
+    // Filling a full array does nothing
+    full.fill_remaining_with(|| unreachable!());
+    full.fill_to_capacity("y".into());
+    assert_eq!(full, ["x", "x", "x"]);
+
+    let mut arr: PushArray<u32, 4> = PushArray::new();
+    arr.push(7);
+    arr.fill_remaining_with(Default::default);
+    assert_eq!(arr, [7, 0, 0, 0]);
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn clone_from_reuses_elements() {
+    let source: PushArray<String, 4> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+
+    let mut longer: PushArray<String, 4> = PushArray::new();
+    longer.push(String::with_capacity(64));
+    longer.push("z".into());
+    longer.push("y".into());
+    longer.clone_from(&source);
+    assert_eq!(longer, ["a", "b"]);
+    // The existing allocation was cloned into, not replaced
+    assert!(longer[0].capacity() >= 64);
+
+    let mut shorter: PushArray<String, 4> = PushArray::new();
+    shorter.push("z".into());
+    shorter.clone_from(&source);
+    assert_eq!(shorter, ["a", "b"]);
+}
+
+#[test]
+fn const_constructors() {
+    use pushy::{
+        FixedIndexMap, GapBuffer, GenArena, Interner, LruCache, MinMaxHeap, PushSlab, PushString,
+        PushWString, SortedMap, Utf8Decoder,
+    };
+    use std::mem::MaybeUninit;
+
+    static ARRAY: PushArray<u8, 4, u8> = PushArray::new();
+    static STRING: PushString<8> = PushString::new();
+    static WIDE_STRING: PushWString<8> = PushWString::new();
+    static SLAB: PushSlab<u32, 4> = PushSlab::new();
+    static ARENA: GenArena<u32, 4> = GenArena::new();
+    static INTERNER: Interner<32, 4> = Interner::new();
+    static LRU: LruCache<u8, u8, 4> = LruCache::new();
+    static MAP: SortedMap<u8, u8, 4> = SortedMap::new();
+    static BUFFERS: DoubleBuffer<u8, 4> = DoubleBuffer::new();
+    static DECODER: Utf8Decoder = Utf8Decoder::new();
+    static GAP_BUFFER: GapBuffer<u8, 4> = GapBuffer::new();
+    static INDEX_MAP: FixedIndexMap<u8, u8, 4> = FixedIndexMap::new();
+    static MIN_MAX_HEAP: MinMaxHeap<u8, 4> = MinMaxHeap::new();
+    // Safety: the first two elements are initialized
+    static FROM_PARTS: PushArray<u8, 4> = unsafe {
+        PushArray::from_raw_parts(
+            [
+                MaybeUninit::new(1),
+                MaybeUninit::new(2),
+                MaybeUninit::uninit(),
+                MaybeUninit::uninit(),
+            ],
+            2,
+        )
+    };
+    // Safety: every element is initialized
+    static COMPACT_FROM_PARTS: PushArray<u8, 300, u16> =
+        unsafe { PushArray::from_raw_parts_with_len_type([MaybeUninit::new(7); 300], 300) };
+
+    assert!(ARRAY.is_empty());
+    assert!(STRING.is_empty());
+    assert!(WIDE_STRING.is_empty());
+    assert!(SLAB.is_empty());
+    assert!(ARENA.is_empty());
+    assert!(INTERNER.is_empty());
+    assert!(LRU.is_empty());
+    assert!(MAP.is_empty());
+    assert!(BUFFERS.front().is_empty());
+    assert!(!DECODER.is_pending());
+    assert!(GAP_BUFFER.is_empty());
+    assert!(INDEX_MAP.is_empty());
+    assert!(MIN_MAX_HEAP.is_empty());
+    assert_eq!(FROM_PARTS, [1, 2]);
+    assert_eq!(COMPACT_FROM_PARTS.len(), 300);
+}
+
+#[test]
+fn c_layout() {
+    #[repr(C)]
+    struct CPushArray {
+        data: [u16; 5],
+        len: u32,
+    }
+
+    type Arr = PushArray<u16, 5, u32>;
+    assert_eq!(Arr::DATA_OFFSET, core::mem::offset_of!(CPushArray, data));
+    assert_eq!(Arr::LEN_OFFSET, core::mem::offset_of!(CPushArray, len));
+    // The high-water mark is an extra field, which C code doesn't know about
+    #[cfg(not(feature = "stats"))]
+    assert_eq!(Arr::SIZE, core::mem::size_of::<CPushArray>());
+    #[cfg(feature = "stats")]
+    assert!(Arr::SIZE > core::mem::size_of::<CPushArray>());
+
+    let mut arr: Arr = PushArray::new();
+    arr.copy_from_slice(&[7, 8]).unwrap();
+
+    // Read the fields the way C code would, going by the offsets alone
+    let base = (&arr as *const Arr).cast::<u8>();
+    // Safety: both offsets are within `arr`, and the first two elements are initialized
+    let (len, data) = unsafe {
+        (
+            base.add(Arr::LEN_OFFSET).cast::<u32>().read(),
+            core::slice::from_raw_parts(base.add(Arr::DATA_OFFSET).cast::<u16>(), 2),
+        )
+    };
+    assert_eq!(len, 2);
+    assert_eq!(data, &[7, 8]);
+}
+
+#[test]
+fn debug_formatting() {
+    let mut arr: PushArray<u8, 4> = PushArray::new();
+    arr.copy_from_slice(&[1, 2]).unwrap();
+
+    assert_eq!(format!("{arr:?}"), "[1, 2]");
+    assert_eq!(format!("{arr:?}"), format!("{:?}", vec![1, 2]));
+    assert_eq!(
+        format!("{arr:#?}"),
+        "PushArray {\n    len: 2,\n    capacity: 4,\n    initialized: [\n        1,\n        2,\n    ],\n}"
+    );
+}
+
+#[test]
+fn subslice_search() {
+    let mut arr: PushArray<u8, 8> = PushArray::new();
+    assert_eq!(arr.find(b""), Some(0));
+    assert_eq!(arr.find(b"a"), None);
+
+    arr.push_str("abcabc").unwrap();
+    assert_eq!(arr.find(b"abc"), Some(0));
+    assert_eq!(arr.find(b"ca"), Some(2));
+    assert_eq!(arr.find(b"bc"), Some(1));
+    assert_eq!(arr.find(b"abcabcd"), None);
+    assert!(arr.contains_subslice(b"cabc"));
+    assert!(!arr.contains_subslice(b"cc"));
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn emplace_in_place() {
+    let mut arr: PushArray<[u64; 64], 2> = PushArray::new();
+
+    let mut guard = arr.emplace().unwrap();
+    let slot = guard.slot().as_mut_ptr();
+    // Safety: the whole element is written
+    let element = unsafe {
+        slot.write_bytes(0, 1);
+        guard.finish()
+    };
+    element[63] = 1;
+
+    // Dropping the guard without finishing commits nothing
+    let _ = arr.emplace().unwrap();
+    assert_eq!(arr.len(), 1);
+    assert_eq!(arr[0][63], 1);
+
+    arr.push([2; 64]);
+    assert!(arr.emplace().is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed_construction() {
+    // Far larger than the stack of a test thread
+    let mut huge = PushArray::<u64, { 4 << 20 }, u32>::new_boxed();
+    assert!(huge.is_empty());
+
+    huge.fill_to_capacity(1);
+    assert_eq!(huge.len(), 4 << 20);
+    assert_eq!(huge.pop(), Some(1));
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn transactions() {
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let mut arr: PushArray<Rc<()>, 4> = PushArray::new();
+    arr.push(Rc::clone(&counter));
+
+    {
+        let mut tx = arr.transaction();
+        tx.push(Rc::clone(&counter));
+        tx.push(Rc::clone(&counter));
+        assert_eq!(tx.len(), 3);
+    }
+    // Rolled back, and the speculative elements were dropped
+    assert_eq!(arr.len(), 1);
+    assert_eq!(Rc::strong_count(&counter), 2);
+
+    let mut tx = arr.transaction();
+    tx.push(Rc::clone(&counter));
+    tx.rollback();
+    assert_eq!(tx.len(), 1);
+    tx.push(Rc::clone(&counter));
+    tx.commit();
+    assert_eq!(arr.len(), 2);
+
+    // Popping past the start of the transaction leaves nothing to roll back
+    let mut tx = arr.transaction();
+    tx.pop();
+    tx.pop();
+    std::mem::drop(tx);
+    assert!(arr.is_empty());
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn pop_arrays() {
+    let mut arr: PushArray<String, 4> = PushArray::new();
+    arr.push_array(["a".to_string(), "b".to_string(), "c".to_string()])
+        .unwrap();
+
+    assert_eq!(arr.pop_array::<4>(), None);
+    assert_eq!(arr.pop_array::<0>(), Some([]));
+    assert_eq!(arr.pop_array(), Some(["b".to_string(), "c".to_string()]));
+    assert_eq!(arr.pop_array(), Some(["a".to_string()]));
+    assert!(arr.is_empty());
+}
+
+#[test]
+fn failed_pushes_give_values_back() {
+    let mut arr: PushArray<Box<u32>, 2> = PushArray::new();
+    arr.try_push(Box::new(1)).unwrap();
+
+    let error = arr.try_push_array([Box::new(2), Box::new(3)]).unwrap_err();
+    assert_eq!(error.value().len(), 2);
+    let [two, three] = error.into_inner();
+
+    arr.try_push(two).unwrap();
+    let error = arr.try_push(three).unwrap_err();
+    assert_eq!(**error.value(), 3);
+    assert!(matches!(
+        pushy::Error::from(error),
+        pushy::Error::NotEnoughCapacity
+    ));
+
+    assert_eq!(arr, [Box::new(1), Box::new(2)]);
+    assert!(arr.try_push_array([]).is_ok());
+}
+
+#[test]
+fn overflow_handler() {
+    // Drop the oldest element to make room
+    let mut recent: PushArray<u32, 2> = PushArray::new();
+    for reading in 1..=4 {
+        recent
+            .push_or_else(reading, |_, recent| {
+                recent.rotate_left(1);
+                recent.pop().is_some()
+            })
+            .unwrap();
+    }
+    assert_eq!(recent, [3, 4]);
+
+    // Give up, after seeing the rejected value
+    let mut rejected = Vec::new();
+    let mut calls = 0;
+    let error = recent
+        .push_or_else(5, |value, _| {
+            calls += 1;
+            rejected.push(*value);
+            false
+        })
+        .unwrap_err();
+    assert_eq!(error.into_inner(), 5);
+    assert_eq!((calls, rejected), (1, vec![5]));
+    assert_eq!(recent, [3, 4]);
+
+    // The handler isn't called when there's room
+    let mut empty: PushArray<u32, 1> = PushArray::new();
+    empty.push_or_else(1, |_, _| unreachable!()).unwrap();
+}
+
+#[test]
+fn truncated_appends() {
+    let mut arr: PushArray<u32, 4> = PushArray::new();
+    assert_eq!(arr.copy_from_slice_truncated(&[1, 2, 3]), 3);
+    assert_eq!(arr.copy_from_slice_truncated(&[4, 5]), 1);
+    assert_eq!(arr.copy_from_slice_truncated(&[6]), 0);
+    assert_eq!(arr, [1, 2, 3, 4]);
+
+    let mut strings: PushArray<String, 2> = PushArray::new();
+    assert_eq!(strings.push_array_truncated([]), 0);
+    assert_eq!(
+        strings.push_array_truncated(["a".into(), "b".into(), "c".into()]),
+        2
+    );
+    assert_eq!(strings, ["a", "b"]);
+}
+
+#[test]
+fn lossy_formatting() {
+    let mut arr: PushArray<u8, 6> = PushArray::new();
+    assert!(arr.format_lossy(format_args!("{}", 12)));
+    // 'ñ' would only half fit, so it's dropped along with the rest
+    assert!(!arr.format_lossy(format_args!("abcñ{}", "c")));
+    assert_eq!(arr.as_str(), Some("12abc"));
+
+    arr.clear();
+    assert!(!arr.format_lossy(format_args!("{}{}", "abc", "defg")));
+    assert_eq!(arr.as_str(), Some("abcdef"));
+
+    struct Failing;
+    impl std::fmt::Display for Failing {
+        fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            Err(std::fmt::Error)
+        }
+    }
+    arr.clear();
+    assert!(!arr.format_lossy(format_args!("x{Failing}")));
+    assert_eq!(arr.as_str(), Some("x"));
+}
+
+#[test]
+fn ascii_case_conversion() {
+    let mut arr: PushArray<u8, 8> = PushArray::new();
+    arr.push_str("aB-ñ").unwrap();
+
+    let mut upper: PushArray<u8, 16, u8> = PushArray::new();
+    arr.to_ascii_uppercase_into(&mut upper).unwrap();
+    arr.to_ascii_lowercase_into(&mut upper).unwrap();
+    assert_eq!(upper.as_str(), Some("AB-ñab-ñ"));
+
+    // Nothing is appended if it doesn't fit
+    let mut small: PushArray<u8, 4> = PushArray::new();
+    assert!(arr.to_ascii_uppercase_into(&mut small).is_err());
+    assert!(small.is_empty());
+
+    // In place, through the slice methods
+    arr.make_ascii_uppercase();
+    assert_eq!(arr.as_str(), Some("AB-ñ"));
+}
+
+#[cfg(not(feature = "no-panic-api"))]
+#[test]
+fn zero_sized_elements() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Clone)]
+    struct Token;
+    impl Drop for Token {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut arr: PushArray<Token, 5, u8> = PushArray::new();
+    #[cfg(not(feature = "stats"))]
+    assert_eq!(core::mem::size_of_val(&arr), 1);
+
+    arr.push(Token);
+    arr.push_array([Token, Token]).unwrap();
+    assert!(arr.push_array([Token, Token, Token]).is_err());
+    assert_eq!(DROPS.swap(0, Ordering::Relaxed), 3);
+    arr.push_repeat(Token, 2).unwrap();
+    assert!(arr.is_fully_initialized());
+    assert!(arr.push_checked(Token).is_err());
+    assert_eq!(DROPS.swap(0, Ordering::Relaxed), 1);
+
+    assert_eq!(arr.iter().count(), 5);
+    assert_eq!(arr.iter().rev().count(), 5);
+    assert_eq!(arr.array_chunks::<2>().count(), 2);
+    assert_eq!(arr.clone().len(), 5);
+    assert_eq!(DROPS.swap(0, Ordering::Relaxed), 5);
+
+    assert!(arr.pop_array::<2>().is_some());
+    assert_eq!(DROPS.swap(0, Ordering::Relaxed), 2);
+
+    let mut cursor = Cursor::new(arr);
+    assert_eq!(cursor.len(), 3);
+    assert!(cursor.next().is_some());
+    assert!(cursor.next_back().is_some());
+    std::mem::drop(cursor);
+    assert_eq!(DROPS.swap(0, Ordering::Relaxed), 3);
+
+    let mut units: PushArray<(), { usize::MAX }> = PushArray::new();
+    units.push_repeat((), 1000).unwrap();
+    assert_eq!(units.len(), 1000);
+    units.clear();
+    assert!(units.is_empty());
+}
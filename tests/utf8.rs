@@ -0,0 +1,48 @@
+use pushy::Utf8Decoder;
+
+fn decode(bytes: &[u8]) -> String {
+    let mut decoder = Utf8Decoder::new();
+    let mut text = String::new();
+    for &byte in bytes {
+        text.extend(decoder.push(byte).iter());
+    }
+    text.extend(decoder.finish());
+
+    text
+}
+
+#[test]
+fn utf8_decoder_matches_std() {
+    let cases: [&[u8]; 10] = [
+        b"plain ascii",
+        "añ€🌍".as_bytes(),
+        b"\xff\xfe",
+        b"a\xc3",
+        b"\xc3a",
+        b"\xe2\x82",
+        b"\xe2\x82a\xe2\x82\xac",
+        b"\xed\xa0\x80",
+        b"\xf0\x80\x80\x80",
+        b"\xf4\x90\x80\x80\xf0\x9f\x8c",
+    ];
+
+    for bytes in cases {
+        assert_eq!(decode(bytes), String::from_utf8_lossy(bytes), "{bytes:?}");
+    }
+}
+
+#[test]
+fn utf8_decoder_yields_chars_as_soon_as_complete() {
+    let mut decoder = Utf8Decoder::new();
+
+    assert!(decoder.push(0xE2).is_empty());
+    assert!(decoder.is_pending());
+    assert!(decoder.push(0x82).is_empty());
+    assert_eq!(decoder.push(0xAC), ['€']);
+    assert!(!decoder.is_pending());
+
+    // An interrupted sequence is replaced, and the interrupting byte decoded
+    decoder.push(0xC3);
+    assert_eq!(decoder.push(b'x'), [char::REPLACEMENT_CHARACTER, 'x']);
+    assert_eq!(decoder.finish(), None);
+}
@@ -0,0 +1,40 @@
+use std::fmt::Write;
+
+use pushy::PushWString;
+
+#[test]
+fn transcoding() {
+    let mut string: PushWString<4> = PushWString::new();
+    assert!(string.is_empty());
+
+    string.push_str("a").unwrap();
+    // Outside of the BMP, so it takes a surrogate pair
+    string.push_char('𝄞').unwrap();
+    assert_eq!(string.as_wide(), &[0x61, 0xd834, 0xdd1e]);
+    assert_eq!(string.len(), 3);
+
+    // Nothing is pushed if it does not fit
+    assert!(string.push_str("bc").is_err());
+    assert!(string.push_char('𝄞').is_err());
+    write!(string, "{}", 1).unwrap();
+
+    assert!(string.chars().eq("a𝄞1".chars()));
+    assert_eq!(string.to_string(), "a𝄞1");
+    assert_eq!(format!("{string:?}"), "\"a𝄞1\"");
+    assert_eq!(string.as_wide(), "a𝄞1".encode_utf16().collect::<Vec<_>>());
+
+    string.clear();
+    assert!(string.is_empty());
+}
+
+#[test]
+fn nul_termination() {
+    let mut string: PushWString<3> = "ab".try_into().unwrap();
+    assert_eq!(string.as_nul_terminated().unwrap(), &[0x61, 0x62, 0]);
+    assert_eq!(string.as_wide(), &[0x61, 0x62]);
+
+    string.push_char('c').unwrap();
+    assert!(string.as_nul_terminated().is_err());
+
+    assert!(PushWString::<2>::try_from("abc").is_err());
+}